@@ -0,0 +1,119 @@
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Attribute, Error, ExprPath, LitStr, Result, Token};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Arg,
+    OptArg,
+    Rest,
+    Flag,
+    KeyValue,
+    KeyStr,
+    Nested,
+    Conflicts,
+}
+
+pub enum DefaultAttr {
+    Trait(Span),
+    Path(ExprPath),
+}
+
+pub struct FieldAttrs {
+    pub kind: Option<(Kind, Span)>,
+    pub rename: Option<LitStr>,
+    pub optional: Option<Span>,
+    pub default: Option<DefaultAttr>,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut output = FieldAttrs {
+            kind: None,
+            rename: None,
+            optional: None,
+            default: None,
+        };
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("parse_attr"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if output.rename.is_some() {
+                        return Err(meta.error("`rename` has been specified"));
+                    }
+                    output.rename = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("optional") {
+                    if output.optional.is_some() {
+                        return Err(meta.error("`optional` has been specified"));
+                    }
+                    output.optional = Some(meta.path.span());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("default") {
+                    if output.default.is_some() {
+                        return Err(meta.error("`default` has been specified"));
+                    }
+                    output.default = Some(if meta.input.peek(Token![=]) {
+                        DefaultAttr::Path(meta.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        DefaultAttr::Trait(meta.path.span())
+                    });
+                    return Ok(());
+                }
+
+                let kind = match meta.path.get_ident().map(|id| id.to_string()).as_deref() {
+                    Some("arg") => Kind::Arg,
+                    Some("opt_arg") => Kind::OptArg,
+                    Some("rest") => Kind::Rest,
+                    Some("flag") => Kind::Flag,
+                    Some("key_value") => Kind::KeyValue,
+                    Some("key_str") => Kind::KeyStr,
+                    Some("nested") => Kind::Nested,
+                    Some("conflicts") => Kind::Conflicts,
+                    _ => return Err(meta.error("unknown `parse_attr` option")),
+                };
+
+                if output.kind.is_some() {
+                    return Err(meta.error("the kind of this field has been specified"));
+                }
+
+                output.kind = Some((kind, meta.path.span()));
+                Ok(())
+            })?;
+        }
+
+        Ok(output)
+    }
+
+    pub fn reject_optional(&self) -> Result<()> {
+        match self.optional {
+            Some(span) => Err(Error::new(span, "`optional` is not supported here")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn reject_default(&self) -> Result<()> {
+        match &self.default {
+            Some(DefaultAttr::Trait(span)) => {
+                Err(Error::new(*span, "`default` is not supported here"))
+            }
+            Some(DefaultAttr::Path(path)) => {
+                Err(Error::new_spanned(path, "`default` is not supported here"))
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn reject_rename(&self) -> Result<()> {
+        match &self.rename {
+            Some(rename) => Err(Error::new(rename.span(), "`rename` is not supported here")),
+            None => Ok(()),
+        }
+    }
+}
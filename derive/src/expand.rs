@@ -0,0 +1,347 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, GenericArgument,
+    Ident, PathArguments, Result, Type,
+};
+
+use crate::attrs::{DefaultAttr, FieldAttrs, Kind};
+
+const MAX_TUPLE_LEN: usize = 20;
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    match &input.data {
+        Data::Struct(data) => expand_struct(&input, data),
+        Data::Enum(data) => expand_enum(&input, data),
+        Data::Union(data) => Err(Error::new(
+            data.union_token.span,
+            "`ParseAttr` cannot be derived for unions",
+        )),
+    }
+}
+
+fn expand_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream> {
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &data.fields,
+            "`ParseAttr` can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut args_ty = vec![];
+    let mut args_pat = vec![];
+    let mut opt_args_ty = vec![];
+    let mut opt_args_pat = vec![];
+    let mut rest_args: Option<(&Type, &Ident)> = None;
+    let mut meta_expr = vec![];
+    let mut meta_pat = vec![];
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let Some((kind, kind_span)) = attrs.kind else {
+            return Err(Error::new_spanned(
+                field,
+                "expected `#[parse_attr(...)]` specifying the kind of this field",
+            ));
+        };
+
+        match kind {
+            Kind::Arg | Kind::OptArg | Kind::Rest | Kind::Conflicts => {
+                attrs.reject_rename()?;
+            }
+            _ => {}
+        }
+
+        match kind {
+            Kind::Arg | Kind::OptArg | Kind::Rest | Kind::Flag => {
+                attrs.reject_optional()?;
+                attrs.reject_default()?;
+            }
+            _ if attrs.optional.is_some() => attrs.reject_default()?,
+            _ => {}
+        }
+
+        let ty = &field.ty;
+        let key = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => unraw(ident),
+        };
+
+        let optional = attrs.optional.is_some();
+        let value_ty = if optional { option_inner(ty)? } else { ty };
+
+        let parser = match kind {
+            Kind::Arg => {
+                args_ty.push(ty);
+                args_pat.push(ident);
+                continue;
+            }
+            Kind::OptArg => {
+                opt_args_ty.push(option_inner(ty)?);
+                opt_args_pat.push(ident);
+                continue;
+            }
+            Kind::Rest => {
+                if rest_args.is_some() {
+                    return Err(Error::new(kind_span, "rest arguments have been specified"));
+                }
+                rest_args = Some((ty, ident));
+                continue;
+            }
+            Kind::Conflicts => {
+                quote_spanned!(kind_span=> <#value_ty as ::attr_parser_fn::meta::ParseConflicts>::conflicts())
+            }
+            Kind::Flag => quote_spanned!(kind_span=> (#key, ::attr_parser_fn::meta::flag())),
+            Kind::KeyValue => {
+                quote_spanned!(kind_span=> (#key, ::attr_parser_fn::meta::key_value::<#value_ty>()))
+            }
+            Kind::KeyStr => {
+                quote_spanned!(kind_span=> (#key, ::attr_parser_fn::meta::key_str::<#value_ty>()))
+            }
+            Kind::Nested => quote_spanned!(kind_span=>
+                (#key, ::attr_parser_fn::meta::list(<#value_ty as ::attr_parser_fn::ParseAttr>::parser()))
+            ),
+        };
+
+        meta_expr.push(match &attrs.default {
+            _ if optional => quote!(::attr_parser_fn::meta::ParseMetaExt::optional(#parser)),
+            Some(DefaultAttr::Trait(_)) => {
+                quote!(::attr_parser_fn::meta::ParseMetaExt::or_default(#parser))
+            }
+            Some(DefaultAttr::Path(path)) => {
+                quote!(::attr_parser_fn::meta::ParseMetaExt::default_with(#parser, #path))
+            }
+            None => parser,
+        });
+        meta_pat.push(quote!(#ident));
+    }
+
+    for (name, len) in [
+        ("positional", args_ty.len()),
+        ("optional", opt_args_ty.len()),
+    ] {
+        if len > MAX_TUPLE_LEN {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("at most {MAX_TUPLE_LEN} {name} arguments are supported"),
+            ));
+        }
+    }
+
+    let args_expr = if args_ty.is_empty() {
+        quote!(.args::<()>())
+    } else {
+        let names = args_pat.iter().map(|ident| unraw(ident));
+        quote!(.named_args((#(::attr_parser_fn::args::arg::<#args_ty>(#names),)*)))
+    };
+
+    let (rest_ty, rest_pat) = match rest_args {
+        Some((ty, ident)) => (quote!(#ty), quote!(#ident)),
+        None => (quote!(()), quote!(())),
+    };
+    let meta_expr = nest_tuple(meta_expr);
+    let meta_pat = nest_tuple(meta_pat);
+    let field_names = fields.named.iter().map(|field| &field.ident);
+
+    let parser = quote! {
+        ::attr_parser_fn::ParseArgs::new()
+            #args_expr
+            .opt_args::<(#(#opt_args_ty,)*)>()
+            .rest_args::<#rest_ty>()
+            .meta(#meta_expr)
+    };
+    let construct = quote! {
+        let ::attr_parser_fn::ParseArgs {
+            args: (#(#args_pat,)*),
+            opt_args: (#(#opt_args_pat,)*),
+            rest_args: #rest_pat,
+            meta: #meta_pat,
+        } = args;
+
+        ::std::result::Result::Ok(Self {
+            #(#field_names,)*
+        })
+    };
+
+    Ok(parse_attr_impl(input, parser, construct))
+}
+
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream> {
+    if data.variants.is_empty() || data.variants.len() > MAX_TUPLE_LEN {
+        return Err(Error::new(
+            input.ident.span(),
+            format!("`ParseAttr` can only be derived for enums with 1 to {MAX_TUPLE_LEN} variants"),
+        ));
+    }
+
+    let mut arms = vec![];
+    for variant in &data.variants {
+        let attrs = FieldAttrs::parse(&variant.attrs)?;
+        attrs.reject_optional()?;
+        attrs.reject_default()?;
+
+        let ident = &variant.ident;
+        let key = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => snake_case(&unraw(ident)),
+        };
+
+        let arm = match &variant.fields {
+            Fields::Unit => {
+                if let Some((_, span)) = attrs.kind {
+                    return Err(Error::new(span, "unit variants are always path only"));
+                }
+                quote!(
+                    ::attr_parser_fn::meta::ParseMetaExt::map(
+                        (#key, ::attr_parser_fn::meta::path_only()),
+                        |_| Self::#ident,
+                    )
+                )
+            }
+
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                let parser = match attrs.kind.unwrap_or((Kind::KeyValue, Span::call_site())) {
+                    (Kind::Flag, span) => quote_spanned!(span=> ::attr_parser_fn::meta::flag()),
+                    (Kind::KeyValue, span) => {
+                        quote_spanned!(span=> ::attr_parser_fn::meta::key_value::<#ty>())
+                    }
+                    (Kind::KeyStr, span) => {
+                        quote_spanned!(span=> ::attr_parser_fn::meta::key_str::<#ty>())
+                    }
+                    (Kind::Nested, span) => quote_spanned!(span=>
+                        ::attr_parser_fn::meta::list(<#ty as ::attr_parser_fn::ParseAttr>::parser())
+                    ),
+                    (_, span) => {
+                        return Err(Error::new(span, "this kind is not supported on variants"))
+                    }
+                };
+                quote!(::attr_parser_fn::meta::ParseMetaExt::map((#key, #parser), Self::#ident))
+            }
+
+            fields => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "only unit variants and variants with exactly one unnamed field are supported",
+                ))
+            }
+        };
+
+        arms.push(arm);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let parse_attr = parse_attr_impl(
+        input,
+        quote! {
+            ::attr_parser_fn::ParseArgs::new()
+                .meta(<Self as ::attr_parser_fn::meta::ParseConflicts>::conflicts())
+        },
+        quote!(::std::result::Result::Ok(args.meta)),
+    );
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::attr_parser_fn::meta::ParseConflicts for #name #ty_generics #where_clause {
+            fn conflicts() -> impl ::attr_parser_fn::meta::ParseMeta<Output = Self> {
+                ::attr_parser_fn::meta::conflicts((#(#arms,)*))
+            }
+        }
+
+        #parse_attr
+    })
+}
+
+// Both entry points go through `ParseArgs`, so that `parse_concat_attrs` keeps
+// track of the attribute each key came from.
+fn parse_attr_impl(
+    input: &DeriveInput,
+    parser: TokenStream,
+    construct: TokenStream,
+) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::attr_parser_fn::ParseAttr for #name #ty_generics #where_clause {
+            fn parse(
+                input: ::attr_parser_fn::__private::syn::parse::ParseStream,
+            ) -> ::attr_parser_fn::__private::syn::Result<Self> {
+                let args = ::attr_parser_fn::ParseAttrTrait::parse(#parser, input)?;
+                #construct
+            }
+
+            fn parse_concat_attrs<'__attr, I>(input: I) -> ::attr_parser_fn::__private::syn::Result<Self>
+            where
+                I: ::std::iter::Iterator<Item = &'__attr ::attr_parser_fn::__private::syn::Attribute>,
+            {
+                let args = ::attr_parser_fn::ParseAttrTrait::parse_concat_attrs(#parser, input)?;
+                #construct
+            }
+        }
+    }
+}
+
+fn nest_tuple(mut items: Vec<TokenStream>) -> TokenStream {
+    while items.len() > MAX_TUPLE_LEN {
+        items = items
+            .chunks(MAX_TUPLE_LEN)
+            .map(|chunk| quote!((#(#chunk,)*)))
+            .collect();
+    }
+
+    quote!((#(#items,)*))
+}
+
+fn option_inner(ty: &Type) -> Result<&Type> {
+    if let Type::Path(path) = ty {
+        if let Some(last) = path.path.segments.last() {
+            if let (true, PathArguments::AngleBracketed(args)) =
+                (last.ident == "Option", &last.arguments)
+            {
+                if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                    return Ok(inner);
+                }
+            }
+        }
+    }
+
+    Err(Error::new(ty.span(), "expected type `Option<T>`"))
+}
+
+fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    match name.strip_prefix("r#") {
+        Some(stripped) => stripped.to_string(),
+        None => name,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut output = String::new();
+
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() {
+            let prev = index.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(index + 1);
+            let boundary = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_uppercase() => next.is_some_and(|next| next.is_lowercase()),
+                _ => false,
+            };
+
+            if boundary {
+                output.push('_');
+            }
+            output.extend(ch.to_lowercase());
+        } else {
+            output.push(ch);
+        }
+    }
+
+    output
+}
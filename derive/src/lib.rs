@@ -0,0 +1,13 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attrs;
+mod expand;
+
+#[proc_macro_derive(ParseAttr, attributes(parse_attr))]
+pub fn derive_parse_attr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
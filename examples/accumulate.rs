@@ -0,0 +1,46 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, LitInt, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new()
+        .meta((
+            ("a", key_value::<LitInt>()),
+            ("b", path_only()),
+            ("c", key_value::<LitStr>()),
+            ("d", key_value::<LitStr>()),
+        ))
+        .accumulate_errors();
+
+    // every problem in the attribute is reported at once
+    let attr: Attribute = parse_quote!(#[my_attr(a = "x", other, b, b, c = 1)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "expected integer literal",
+            "attribute `other` is not expected\nhelp: valid attributes are `a`, `b`, `c`, `d`",
+            "path `b` has been specified",
+            "first specified here",
+            "expected string literal",
+            "attribute `d` must be specified",
+        ]
+    );
+
+    // keys that failed to parse are not also reported missing
+    let attr: Attribute = parse_quote!(#[my_attr(a = 1, c = "x", d = 2)]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    // without accumulating, parsing stops at the first error
+    let attr: Attribute = parse_quote!(#[my_attr(a = "x", e)]);
+    let err = ParseArgs::new()
+        .meta((("a", key_value::<LitInt>()), ("b", path_only())))
+        .parse_attr(&attr)
+        .unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, ["expected integer literal"]);
+}
@@ -0,0 +1,82 @@
+use std::fmt::Write;
+
+use attr_parser_fn::{
+    meta::{key_value, path_only, ParseMeta},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{meta::ParseNestedMeta, parse_quote, Attribute, LitStr, Meta, Result};
+
+// A parser written without `visit_keys`, counting how often `c` is given.
+struct Count(usize);
+
+impl ParseMeta for Count {
+    type Output = usize;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        write!(f, "`c`")
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        if !nested.path.is_ident("c") {
+            return Ok(false);
+        }
+        self.0 += 1;
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        Ok(self.0)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        true
+    }
+}
+
+fn main() {
+    let parser = ParseArgs::new()
+        .meta((("name", key_value::<LitStr>()), ("skip", path_only())))
+        .catch_all::<Meta>();
+
+    // unknown keys are captured, duplicates included
+    let attr: Attribute = parse_quote! {
+        #[my_attr(name = "x", with(a), rename_all = "camelCase", with(b))]
+    };
+    let ((name, skip), unknown) = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert_eq!(name.value(), "x");
+    assert!(!skip);
+    assert_eq!(unknown.len(), 3);
+    assert!(unknown[0].path().is_ident("with"));
+    assert!(unknown[2].path().is_ident("with"));
+
+    // a declared key in the wrong form is still an error
+    let attr: Attribute = parse_quote!(#[my_attr(name(x))]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`name` expects `name = ...`, found `name(...)`"
+    );
+
+    // and so is a declared key given twice
+    let attr: Attribute = parse_quote!(#[my_attr(name = "x", name = "y")]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "path `name` has been specified");
+
+    // without a schema every key is captured
+    let attr: Attribute = parse_quote!(#[my_attr(name = "x", skip)]);
+    let captured = ParseArgs::new()
+        .catch_all::<Meta>()
+        .parse_attr(&attr)
+        .unwrap()
+        .meta
+        .1;
+    assert_eq!(captured.len(), 2);
+
+    // keys of parsers that do not report them are checked for duplicates too
+    let attr: Attribute = parse_quote!(#[my_attr(c, c)]);
+    let err = ParseArgs::new()
+        .meta(Count(0))
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "path `c` has been specified");
+}
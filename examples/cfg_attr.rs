@@ -0,0 +1,82 @@
+use attr_parser_fn::{
+    find_attr::{self, Cfgs},
+    meta::{key_value, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, LitStr};
+
+fn main() {
+    let attrs: Vec<Attribute> = parse_quote! {
+        #[cfg_attr(feature = "serde", my_attr(skip))]
+        #[cfg_attr(all(test, not(feature = "serde")), my_attr(rename = "a"))]
+        #[cfg_attr(any(unix, windows), cfg_attr(test, my_attr(rename = "b")))]
+        #[other]
+    };
+    let parser = ParseArgs::new().meta((
+        ("skip", path_only()),
+        ("rename", key_value::<LitStr>()).optional(),
+    ));
+
+    // without a `Cfgs` every wrapped attribute is kept
+    let found = find_attr::all_expanded(&attrs, "my_attr", None).unwrap();
+    assert_eq!(found.len(), 3);
+
+    let cfgs = Cfgs::new().feature("serde").flag("unix");
+    let found = find_attr::all_expanded(&attrs, "my_attr", Some(&cfgs)).unwrap();
+    let (skip, rename) = parser
+        .clone()
+        .parse_concat_attrs(found.iter())
+        .unwrap()
+        .meta;
+    assert!(skip);
+    assert!(rename.is_none());
+
+    // nested `cfg_attr` is expanded as well
+    let cfgs = Cfgs::new().flag("test").flag("windows");
+    let found = find_attr::all_expanded(&attrs, "my_attr", Some(&cfgs)).unwrap();
+    let err = parser.parse_concat_attrs(found.iter()).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "path `rename` has been specified, here in `#[my_attr]`",
+            "first specified here, in an earlier `#[my_attr]` attribute",
+        ]
+    );
+
+    let cfgs = Cfgs::new().flag("test");
+    let found = find_attr::first_expanded(&attrs, "my_attr", Some(&cfgs)).unwrap();
+    assert!(found.is_some());
+    let err = find_attr::only_expanded(&attrs, "my_attr", None).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "conflicting declaration of attribute `my_attr`"
+    );
+
+    // malformed predicates are reported once a `Cfgs` evaluates them
+    let error = |attr: Attribute| {
+        find_attr::expand_cfg_attr(&[attr], Some(&Cfgs::new()))
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(
+        error(parse_quote!(#[cfg_attr(not(a, b), my_attr)])),
+        "`not` expects exactly one predicate"
+    );
+    assert_eq!(
+        error(parse_quote!(#[cfg_attr(either(a), my_attr)])),
+        "unknown cfg predicate `either`"
+    );
+    assert_eq!(
+        error(parse_quote!(#[cfg_attr(feature = 1, my_attr)])),
+        "expected string literal"
+    );
+    assert_eq!(
+        error(parse_quote!(#[cfg_attr(a::b, my_attr)])),
+        "expected identifier"
+    );
+    assert_eq!(
+        error(parse_quote!(#[cfg_attr(all(a, any(b, not(c = 1))), my_attr)])),
+        "expected string literal"
+    );
+}
@@ -0,0 +1,118 @@
+use attr_parser_fn::{
+    find_attr,
+    meta::{key_value, one_of, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, DeriveInput, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new().meta((("name", key_value::<LitStr>()), ("skip", path_only())));
+
+    let input: DeriveInput = parse_quote! {
+        #[my_attr(name = "a")]
+        #[my_attr]
+        #[my_attr(skip)]
+        struct S;
+    };
+    let (name, skip) = parser
+        .clone()
+        .parse_concat_attrs(find_attr::all(&input.attrs, "my_attr"))
+        .unwrap()
+        .meta;
+    assert_eq!(name.value(), "a");
+    assert!(skip);
+
+    // both the duplicate and the first `name` say which attribute they came from
+    let input: DeriveInput = parse_quote! {
+        #[first(name = "a")]
+        #[second(skip, name = "b")]
+        struct S;
+    };
+    let err = parser
+        .clone()
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap_err();
+    assert_eq!(
+        messages(err),
+        [
+            "path `name` has been specified, here in `#[second]`",
+            "first specified here, in an earlier `#[first]` attribute",
+        ]
+    );
+
+    // keys within one attribute are not told apart
+    let input: DeriveInput = parse_quote! {
+        #[first(skip)]
+        #[second(name = "a", name = "b")]
+        struct S;
+    };
+    let err = parser.parse_concat_attrs(input.attrs.iter()).unwrap_err();
+    assert_eq!(
+        messages(err),
+        ["path `name` has been specified", "first specified here"]
+    );
+
+    // items reordered by interleaved parsing keep their attribute
+    let interleaved = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .meta(("name", key_value::<LitStr>()))
+        .interleaved();
+    let input: DeriveInput = parse_quote! {
+        #[first(name = "a")]
+        #[second("x", name = "b")]
+        struct S;
+    };
+    let err = interleaved
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap_err();
+    assert_eq!(
+        messages(err),
+        [
+            "path `name` has been specified, here in `#[second]`",
+            "first specified here, in an earlier `#[first]` attribute",
+        ]
+    );
+
+    // and so do keys given positionally
+    let positional = ParseArgs::new().meta(("name", key_value::<LitStr>()).positional());
+    let input: DeriveInput = parse_quote! {
+        #[first("a")]
+        #[second(name = "b")]
+        struct S;
+    };
+    let err = positional
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap_err();
+    assert_eq!(
+        messages(err),
+        [
+            "`name` has already been given as a positional argument, here in `#[second]`",
+            "first specified here, in an earlier `#[first]` attribute",
+        ]
+    );
+
+    // conflicting keys name both attributes as well
+    let conflicting = ParseArgs::new().meta(one_of((
+        ("name", key_value::<LitStr>()),
+        ("skip", path_only()),
+    )));
+    let input: DeriveInput = parse_quote! {
+        #[first(skip)]
+        #[second(name = "a")]
+        struct S;
+    };
+    let err = conflicting
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap_err();
+    assert_eq!(
+        messages(err),
+        [
+            "attribute `name` is conflicts with `skip`, here in `#[second]`",
+            "conflicting `skip` given here, in an earlier `#[first]` attribute",
+        ]
+    );
+}
+
+fn messages(err: syn::Error) -> Vec<String> {
+    err.into_iter().map(|err| err.to_string()).collect()
+}
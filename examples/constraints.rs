@@ -0,0 +1,78 @@
+use attr_parser_fn::{
+    meta::{key_str, key_value, meta_list, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Ident, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new().meta(
+        (
+            ("getter", path_only()),
+            ("getter_name", key_value::<Ident>()).optional(),
+            ("skip", path_only()),
+            ("rename", key_str::<Ident>()).optional(),
+            ("path", key_value::<LitStr>()).optional(),
+            ("inline", path_only()),
+            ("serde", meta_list(("flatten", path_only()))).optional(),
+        )
+            .requires("getter_name", "getter")
+            .excludes("skip", "rename")
+            .required_unless("path", "inline")
+            .excludes("serde.flatten", "inline"),
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(getter, getter_name = get_x, inline)]);
+    assert!(parser.clone().parse_attr(&attr).is_ok());
+
+    let attr: Attribute = parse_quote!(#[my_attr(getter_name = get_x, inline)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "`getter_name` requires `getter`");
+
+    let attr: Attribute = parse_quote!(#[my_attr(skip, rename = "y", inline)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "`skip` cannot be used together with `rename`",
+            "`rename` is given here",
+        ]
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(skip)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`path` must be specified unless `inline` is given"
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(inline, serde(flatten))]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`serde.flatten` cannot be used together with `inline`"
+    );
+
+    // names the schema does not declare are reported
+    let attr: Attribute = parse_quote!(#[my_attr(skip)]);
+    let err = ParseArgs::new()
+        .meta(
+            (
+                ("skip", path_only()),
+                ("rename", key_str::<Ident>()).optional(),
+                ("serde", meta_list(("flatten", path_only()))).optional(),
+            )
+                .excludes("skip", "renam")
+                .requires("serde.flaten", "skip"),
+        )
+        .parse_attr(&attr)
+        .unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "constraint refers to unknown key `renam`\nhelp: did you mean `rename`?",
+            "constraint refers to unknown key `serde.flaten`\nhelp: did you mean `serde.flatten`?",
+        ]
+    );
+}
@@ -0,0 +1,104 @@
+use attr_parser_fn::ParseAttr;
+use syn::{parse_quote, Attribute, DeriveInput, Expr, Ident, Lit, LitStr, Type};
+
+#[derive(ParseAttr)]
+struct MyAttr {
+    #[parse_attr(arg)]
+    first: LitStr,
+    #[parse_attr(arg)]
+    second: LitStr,
+    #[parse_attr(opt_arg)]
+    third: Option<Lit>,
+    #[parse_attr(rest)]
+    rest: Vec<Lit>,
+    #[parse_attr(flag)]
+    path_only: bool,
+    #[parse_attr(key_value)]
+    key_value: Type,
+    #[parse_attr(key_value, optional)]
+    kv_optional: Option<Expr>,
+    #[parse_attr(key_str, rename = "type")]
+    ty: Type,
+    #[parse_attr(conflicts)]
+    conf: Mode,
+    #[parse_attr(nested)]
+    nested: Nested,
+    #[parse_attr(key_str, default = "default_name")]
+    name: Ident,
+}
+
+fn default_name() -> Ident {
+    parse_quote!(unnamed)
+}
+
+#[derive(ParseAttr)]
+enum Mode {
+    Conf1,
+    #[parse_attr(rename = "conf1")]
+    Conf1Expr(Expr),
+    Conf2(Expr),
+}
+
+#[derive(ParseAttr)]
+struct Nested {
+    #[parse_attr(flag)]
+    milk: bool,
+    #[parse_attr(conflicts, default)]
+    tea: Tea,
+}
+
+#[derive(ParseAttr, PartialEq, Debug, Default)]
+enum Tea {
+    #[default]
+    RedTea,
+    GreenTea,
+}
+
+fn main() {
+    let attr: Attribute = parse_quote! {
+        #[my_attr(
+            "hello",
+            "world",
+            122,
+            conf1 = 114 + 514,
+            key_value = SomeType<A, B>,
+            type = "Vec<u8>",
+            path_only,
+            nested(green_tea))
+        ]
+    };
+
+    let my_attr = MyAttr::parse_attr(&attr).unwrap();
+
+    assert_eq!(my_attr.first.value(), "hello");
+    assert_eq!(my_attr.second.value(), "world");
+    assert!(my_attr.third.is_some());
+    assert!(my_attr.rest.is_empty());
+    assert!(my_attr.path_only);
+    assert!(matches!(my_attr.key_value, Type::Path(_)));
+    assert!(my_attr.kv_optional.is_none());
+    assert!(matches!(my_attr.ty, Type::Path(_)));
+    match my_attr.conf {
+        Mode::Conf1Expr(Expr::Binary(_)) => {}
+        Mode::Conf1Expr(expr) | Mode::Conf2(expr) => unreachable!("{expr:?}"),
+        Mode::Conf1 => unreachable!(),
+    }
+    assert!(!my_attr.nested.milk);
+    assert_eq!(my_attr.nested.tea, Tea::GreenTea);
+    assert_eq!(my_attr.name, "unnamed");
+    // derived types name the attributes of conflicting keys too
+    let input: DeriveInput = parse_quote! {
+        #[first(red_tea)]
+        #[second(green_tea)]
+        struct S;
+    };
+    let err = Tea::parse_concat_attrs(input.attrs.iter()).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "attribute `green_tea` is conflicts with `red_tea`, here in `#[second]`",
+            "conflicting `red_tea` given here, in an earlier `#[first]` attribute",
+        ]
+    );
+}
@@ -0,0 +1,72 @@
+use attr_parser_fn::{
+    meta::{key_value, list, path_only, ParseMetaUnnamed},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{meta::ParseNestedMeta, parse_quote, Attribute, LitInt, Result, Token};
+
+// A path-only parser written without `forms`.
+struct Plain(bool);
+
+impl ParseMetaUnnamed for Plain {
+    type Output = bool;
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        if !nested.input.is_empty() && !nested.input.peek(Token![,]) {
+            return Ok(false);
+        }
+        self.0 = true;
+        Ok(true)
+    }
+
+    fn finish(self) -> Option<Self::Output> {
+        Some(self.0)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        true
+    }
+}
+
+fn error(attr: Attribute) -> String {
+    ParseArgs::new()
+        .meta((
+            ("skip", path_only()),
+            ("rename", key_value::<LitInt>()),
+            ("with", list(ParseArgs::new().args::<(LitInt,)>())),
+            ("plain", Plain(false)),
+        ))
+        .parse_attr(&attr)
+        .err()
+        .unwrap()
+        .to_string()
+}
+
+fn main() {
+    assert_eq!(
+        error(parse_quote!(#[my_attr(renam = 1)])),
+        "attribute `renam` is not expected\nhelp: did you mean `rename`?"
+    );
+    assert_eq!(
+        error(parse_quote!(#[my_attr(other)])),
+        "attribute `other` is not expected\n\
+         help: valid attributes are `skip`, `rename`, `with`, `plain`"
+    );
+    assert_eq!(
+        error(parse_quote!(#[my_attr(skip = 1)])),
+        "`skip` is a flag and takes no value"
+    );
+    assert_eq!(
+        error(parse_quote!(#[my_attr(rename(1))])),
+        "`rename` expects `rename = ...`, found `rename(...)`"
+    );
+    assert_eq!(
+        error(parse_quote!(#[my_attr(with = 1)])),
+        "`with` expects `with(...)`, found `with = ...`"
+    );
+
+    // a parser that does not report its forms gets the generic message
+    assert_eq!(
+        error(parse_quote!(#[my_attr(plain = 1)])),
+        "the calling form of attribute `plain` is not compliant"
+    );
+}
@@ -0,0 +1,53 @@
+use attr_parser_fn::{meta::flag, ParseArgs, ParseAttrTrait};
+use syn::{parse_quote, Attribute};
+
+fn main() {
+    let parse = |attr: Attribute, allow_list: bool| {
+        let flag = if allow_list {
+            flag().allow_list()
+        } else {
+            flag()
+        };
+        ParseArgs::new()
+            .meta(("skip", flag))
+            .parse_attr(&attr)
+            .map(|args| args.meta)
+            .map_err(|err| err.to_string())
+    };
+
+    assert_eq!(parse(parse_quote!(#[my_attr(skip)]), false), Ok(true));
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip = true)]), false),
+        Ok(true)
+    );
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip = false)]), false),
+        Ok(false)
+    );
+    assert_eq!(parse(parse_quote!(#[my_attr()]), false), Ok(false));
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip(false))]), true),
+        Ok(false)
+    );
+
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip = 1)]), false),
+        Err("expected `true` or `false`, found `1`".to_string())
+    );
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip = "yes")]), false),
+        Err("expected `true` or `false`, found `\"yes\"`".to_string())
+    );
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip(true, false))]), true),
+        Err("expected `true` or `false` only".to_string())
+    );
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip())]), true),
+        Err("unexpected end of input, expected `true` or `false`".to_string())
+    );
+    assert_eq!(
+        parse(parse_quote!(#[my_attr(skip(true))]), false),
+        Err("`skip` expects `skip` or `skip = ...`, found `skip(...)`".to_string())
+    );
+}
@@ -0,0 +1,61 @@
+use attr_parser_fn::{
+    meta::{any_of, at_least_one, at_most, key_value, path_only},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Ident};
+
+fn main() {
+    let accessors = || {
+        (
+            ("get", path_only()),
+            ("set", path_only()),
+            ("get_mut", key_value::<Ident>()),
+        )
+    };
+
+    // any subset may be given, and each member reports its value
+    let attr: Attribute = parse_quote!(#[my_attr(get_mut = f, get)]);
+    let (get, set, get_mut) = ParseArgs::new()
+        .meta(any_of(accessors()))
+        .parse_attr(&attr)
+        .unwrap()
+        .meta;
+    assert_eq!(get, Some(true));
+    assert_eq!(set, None);
+    assert_eq!(get_mut.unwrap(), "f");
+
+    let attr: Attribute = parse_quote!(#[my_attr()]);
+    let (get, set, get_mut) = ParseArgs::new()
+        .meta(any_of(accessors()))
+        .parse_attr(&attr)
+        .unwrap()
+        .meta;
+    assert!(get.is_none() && set.is_none() && get_mut.is_none());
+
+    let err = ParseArgs::new()
+        .meta(at_least_one(accessors()))
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "at least one of following attributes must be provided: `get`, `set`, `get_mut`"
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(get, set, get_mut = f)]);
+    let err = ParseArgs::new()
+        .meta(at_most(accessors(), 2))
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "attribute `get_mut` is one too many, at most 2 of following attributes may be provided: `get`, `set`, `get_mut`"
+    );
+
+    // members are still checked for duplicates
+    let attr: Attribute = parse_quote!(#[my_attr(get, get)]);
+    let err = ParseArgs::new()
+        .meta(any_of(accessors()))
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "path `get` has been specified");
+}
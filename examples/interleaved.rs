@@ -0,0 +1,78 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Ident, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .rest_args::<Vec<Ident>>()
+        .meta((
+            ("short", path_only()),
+            ("long", key_value::<LitStr>()).optional(),
+        ))
+        .interleaved();
+
+    // `first` and `second` are not declared keys, so they stay positional
+    // values, in the order they were written around the meta items
+    let attr: Attribute = parse_quote! {
+        #[arg(short, "help text", first, long = "x", second)]
+    };
+    let ParseArgs {
+        args: (help,),
+        rest_args,
+        meta: (short, long),
+        ..
+    } = parser.clone().parse_attr(&attr).unwrap();
+
+    assert_eq!(help.value(), "help text");
+    assert_eq!(rest_args, ["first", "second"]);
+    assert!(short);
+    assert_eq!(long.unwrap().value(), "x");
+
+    let attr: Attribute = parse_quote! {
+        #[arg(long = "x", "help text", second, short, first)]
+    };
+    let ParseArgs {
+        args: (help,),
+        rest_args,
+        ..
+    } = parser.parse_attr(&attr).unwrap();
+
+    assert_eq!(help.value(), "help text");
+    assert_eq!(rest_args, ["second", "first"]);
+
+    // errors can be accumulated whichever way round the modes are chosen
+    let schema = || {
+        (
+            ("short", path_only()),
+            ("long", key_value::<LitStr>()).optional(),
+        )
+    };
+    let attr: Attribute = parse_quote!(#[arg(short = 1, "help text", long)]);
+    let expected = [
+        "`short` is a flag and takes no value",
+        "`long` expects `long = ...`, found `long`",
+    ];
+
+    let err = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .meta(schema())
+        .interleaved()
+        .accumulate_errors()
+        .parse_attr(&attr)
+        .unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, expected);
+
+    let err = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .meta(schema())
+        .accumulate_errors()
+        .interleaved()
+        .parse_attr(&attr)
+        .unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, expected);
+}
@@ -0,0 +1,91 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, DeriveInput, Ident, LitStr};
+
+fn main() {
+    // the value of a name-value attribute is the only positional argument
+    let attr: Attribute = parse_quote!(#[doc = " hello"]);
+    let doc = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .parse_attr(&attr)
+        .unwrap()
+        .args
+        .0;
+    assert_eq!(doc.value(), " hello");
+
+    let parser = ParseArgs::new()
+        .meta((
+            ("skip", path_only()),
+            ("rename", key_value::<Ident>()).optional(),
+        ))
+        .embedded_str();
+
+    let attr: Attribute = parse_quote!(#[my_attr = "skip, rename = x"]);
+    let (skip, rename) = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert!(skip);
+    assert_eq!(rename.unwrap(), "x");
+
+    let attr: Attribute = parse_quote!(#[my_attr("skip", "rename = x")]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected a single string literal");
+
+    let attr: Attribute = parse_quote!(#[my_attr = 1]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    // errors inside the string are reported as for a list
+    let attr: Attribute = parse_quote!(#[my_attr = "skip = 1"]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "`skip` is a flag and takes no value");
+
+    let attr: Attribute = parse_quote!(#[my_attr = "skip, other"]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "attribute `other` is not expected\nhelp: valid attributes are `skip`, `rename`"
+    );
+
+    let attr: Attribute = parse_quote!(#[doc = 1]);
+    let err = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    // each attribute's string is parsed, then the contents are joined
+    let input: DeriveInput = parse_quote! {
+        #[my_attr = "skip"]
+        struct S;
+    };
+    let (skip, rename) = parser
+        .clone()
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap()
+        .meta;
+    assert!(skip);
+    assert!(rename.is_none());
+
+    let input: DeriveInput = parse_quote! {
+        #[my_attr = "skip,"]
+        #[my_attr]
+        #[my_attr("rename = x")]
+        struct S;
+    };
+    let (skip, rename) = parser
+        .clone()
+        .parse_concat_attrs(input.attrs.iter())
+        .unwrap()
+        .meta;
+    assert!(skip);
+    assert_eq!(rename.unwrap(), "x");
+
+    let input: DeriveInput = parse_quote! {
+        #[my_attr = "skip"]
+        #[my_attr = "skip"]
+        struct S;
+    };
+    let err = parser.parse_concat_attrs(input.attrs.iter()).unwrap_err();
+    assert_eq!(err.to_string(), "path `skip` has been specified");
+}
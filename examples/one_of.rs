@@ -0,0 +1,77 @@
+use attr_parser_fn::{
+    meta::{key_value, one_of, path_only, OneOf2, OneOf3},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Expr, Ident, LitInt, LitStr};
+
+fn main() {
+    // arms of a conflict group keep their own output types
+    let parser = ParseArgs::new().meta(one_of((
+        ("conf1", path_only()),
+        ("conf1", key_value::<Expr>()),
+        ("conf2", key_value::<LitInt>()),
+    )));
+
+    let attr: Attribute = parse_quote!(#[my_attr(conf1)]);
+    let meta = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert!(matches!(meta, OneOf3::A(true)));
+
+    let attr: Attribute = parse_quote!(#[my_attr(conf1 = 1 + 2)]);
+    let meta = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert!(matches!(meta, OneOf3::B(Expr::Binary(_))));
+
+    let attr: Attribute = parse_quote!(#[my_attr(conf1, conf2 = 3)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "attribute `conf2` is conflicts with `conf1`",
+            "conflicting `conf1` given here",
+        ]
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr()]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "one of following attributes must be provided: `conf1`, `conf1`, `conf2`"
+    );
+
+    // a positional argument may accept one of several syntaxes
+    let parser = ParseArgs::new()
+        .args::<(OneOf2<LitStr, Ident>,)>()
+        .opt_args::<(OneOf2<LitInt, LitStr>,)>();
+
+    let attr: Attribute = parse_quote!(#[table(users)]);
+    let (table,) = parser.clone().parse_attr(&attr).unwrap().args;
+    assert!(matches!(table, OneOf2::B(_)));
+
+    let attr: Attribute = parse_quote!(#[table("users", 3)]);
+    let ParseArgs {
+        args: (table,),
+        opt_args: (limit,),
+        ..
+    } = parser.clone().parse_attr(&attr).unwrap();
+    assert!(matches!(table, OneOf2::A(_)));
+    assert!(matches!(limit, Some(OneOf2::A(_))));
+
+    let attr: Attribute = parse_quote!(#[table(1)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal or identifier");
+
+    let attr: Attribute = parse_quote!(#[table(users, 1.5)]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected integer literal or string literal"
+    );
+
+    let attr: Attribute = parse_quote!(#[route(GET, "/a", "/b")]);
+    let rest_args = ParseArgs::new()
+        .rest_args::<Vec<OneOf2<Ident, LitStr>>>()
+        .parse_attr(&attr)
+        .unwrap()
+        .rest_args;
+    assert_eq!(rest_args.len(), 3);
+}
@@ -0,0 +1,52 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Ident, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new().meta((
+        ("path", key_value::<LitStr>()).positional(),
+        ("method", key_value::<Ident>()),
+    ));
+
+    let by_position: Attribute = parse_quote!(#[route("/users", method = GET)]);
+    let by_name: Attribute = parse_quote!(#[route(path = "/users", method = GET)]);
+    for attr in [by_position, by_name] {
+        let (path, method) = parser.clone().parse_attr(&attr).unwrap().meta;
+        assert_eq!(path.value(), "/users");
+        assert_eq!(method, "GET");
+    }
+
+    // giving the key both ways is a duplicate, reported at the named one
+    let both: Attribute = parse_quote!(#[route("/users", method = GET, path = "/posts")]);
+    let err = parser.parse_attr(&both).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "`path` has already been given as a positional argument",
+            "first specified here",
+        ]
+    );
+
+    // keys are matched after trimming, so they are filled the same way
+    let attr: Attribute = parse_quote!(#[x("v")]);
+    let (value,) = ParseArgs::new()
+        .meta((("a ", key_value::<LitStr>()).positional(),))
+        .parse_attr(&attr)
+        .unwrap()
+        .meta;
+    assert_eq!(value.value(), "v");
+
+    // a key without a value form cannot take a positional value
+    let attr: Attribute = parse_quote!(#[x(v)]);
+    let err = ParseArgs::new()
+        .meta((("f", path_only()).positional(),))
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`f` takes no value, so it cannot be given positionally"
+    );
+}
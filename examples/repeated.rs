@@ -0,0 +1,34 @@
+use attr_parser_fn::{
+    meta::{key_str, key_value, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Ident, WherePredicate};
+
+fn main() {
+    // a parser with repeated keys can be cloned and used again
+    let parser = ParseArgs::new().meta((
+        ("method", key_value::<Ident>()).repeated().min(1).max(2),
+        ("bound", key_str::<WherePredicate>()).repeated(),
+    ));
+
+    let attr: Attribute = parse_quote! {
+        #[route(method = GET, bound = "T: Clone", method = POST)]
+    };
+    let (methods, bounds) = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert_eq!(methods, ["GET", "POST"]);
+    assert_eq!(bounds.len(), 1);
+
+    let attr: Attribute = parse_quote!(#[route(method = GET, method = POST, method = PUT)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "attribute `method` can be specified at most 2 times"
+    );
+
+    let attr: Attribute = parse_quote!(#[route(bound = "T: Clone")]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "attribute `method` must be specified at least 1 times, found 0"
+    );
+}
@@ -0,0 +1,110 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only},
+    ParseArgs, ParseAttrTrait,
+};
+use std::collections::{BTreeSet, HashSet};
+
+use attr_parser_fn::rest_args::Bounded;
+use proc_macro2::TokenStream;
+use syn::{
+    parse_quote, punctuated::Punctuated, Attribute, Ident, LitInt, LitStr, Path, Token, Type,
+};
+
+fn main() {
+    // rest arguments of types without `Token` stop before the first meta item
+    let attr: Attribute = parse_quote!(#[my_attr(Vec<u8>, String, k = 1)]);
+    let ParseArgs {
+        rest_args, meta, ..
+    } = ParseArgs::new()
+        .rest_args::<Vec<Type>>()
+        .meta(("k", key_value::<LitInt>()))
+        .parse_attr(&attr)
+        .unwrap();
+    assert_eq!(rest_args.len(), 2);
+    assert_eq!(meta.base10_parse::<u8>().unwrap(), 1);
+
+    // a bare path after the values can still be a flag
+    let parser = ParseArgs::new()
+        .rest_args::<Vec<LitStr>>()
+        .meta((("k", key_value::<LitInt>()), ("skip", path_only())));
+    let attr: Attribute = parse_quote!(#[my_attr("a", "b", skip, k = 1)]);
+    let ParseArgs {
+        rest_args,
+        meta: (_, skip),
+        ..
+    } = parser.clone().parse_attr(&attr).unwrap();
+    assert_eq!(rest_args.len(), 2);
+    assert!(skip);
+
+    // a value that is not a meta item reports why it does not parse
+    let attr: Attribute = parse_quote!(#[my_attr("a", 5, k = 1)]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    let parser = ParseArgs::new()
+        .rest_args::<Punctuated<LitStr, Token![|]>>()
+        .meta(("k", key_value::<LitInt>()));
+    let attr: Attribute = parse_quote!(#[my_attr("a" | "b" | "c", k = 1)]);
+    let rest_args = parser.clone().parse_attr(&attr).unwrap().rest_args;
+    assert_eq!(rest_args.len(), 3);
+
+    let attr: Attribute = parse_quote!(#[my_attr("a" | 5, k = 1)]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "expected string literal");
+
+    // sets reject duplicate values
+    let attr: Attribute = parse_quote!(#[derive_more(Clone, Debug, Clone)]);
+    let err = ParseArgs::new()
+        .rest_args::<HashSet<Ident>>()
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "duplicate value `Clone`");
+    let err = ParseArgs::new()
+        .rest_args::<BTreeSet<Ident>>()
+        .parse_attr(&attr)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "duplicate value `Clone`");
+
+    // separators other than commas
+    let attr: Attribute = parse_quote!(#[bounds(Clone + Send, skip)]);
+    let ParseArgs {
+        rest_args,
+        meta: skip,
+        ..
+    } = ParseArgs::new()
+        .rest_args::<Punctuated<Path, Token![+]>>()
+        .meta(("skip", path_only()))
+        .parse_attr(&attr)
+        .unwrap();
+    assert_eq!(rest_args.len(), 2);
+    assert!(skip);
+
+    // a raw tail of tokens up to the first meta item
+    let attr: Attribute = parse_quote!(#[my_attr(a + b, c d, k = 1)]);
+    let rest_args = ParseArgs::new()
+        .rest_args::<TokenStream>()
+        .meta(("k", key_value::<LitInt>()))
+        .parse_attr(&attr)
+        .unwrap()
+        .rest_args;
+    assert_eq!(rest_args.to_string(), "a + b , c d");
+
+    // the number of values can be limited
+    let parser = ParseArgs::new().rest_args::<Bounded<Vec<LitInt>, 2, 3>>();
+    let attr: Attribute = parse_quote!(#[my_attr(1, 2)]);
+    assert_eq!(parser.clone().parse_attr(&attr).unwrap().rest_args.len(), 2);
+
+    let attr: Attribute = parse_quote!(#[my_attr(1)]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected at least 2 positional values, found 1"
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(1, 2, 3, 4)]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected at most 3 positional values, found 4"
+    );
+}
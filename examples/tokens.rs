@@ -0,0 +1,55 @@
+use attr_parser_fn::{
+    meta::{key_value, path_only},
+    ParseArgs, ParseAttrTrait,
+};
+use quote::quote;
+use syn::{DeriveInput, LitInt, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new()
+        .args::<(LitStr,)>()
+        .meta((("skip", path_only()), ("limit", key_value::<LitInt>())));
+
+    // the arguments of an attribute macro arrive without the attribute
+    let args = parser
+        .clone()
+        .parse_tokens(quote!("name", skip, limit = 3))
+        .unwrap();
+    assert_eq!(args.args.0.value(), "name");
+    assert!(args.meta.0);
+
+    let err = parser
+        .clone()
+        .parse_tokens(quote!("name", limit = "3"))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected integer literal");
+
+    // arguments and item are parsed together
+    let (args, item) = parser
+        .clone()
+        .parse_attribute_macro::<DeriveInput>(
+            quote!("name", limit = 3),
+            quote!(
+                struct S;
+            ),
+        )
+        .unwrap();
+    assert!(!args.meta.0);
+    assert_eq!(item.ident, "S");
+
+    // errors come back as `compile_error!` invocations, followed by the item
+    let tokens = parser
+        .parse_attribute_macro::<DeriveInput>(
+            quote!(limit = 3),
+            quote!(
+                struct S;
+            ),
+        )
+        .map(|_| ())
+        .unwrap_err();
+    let expected = quote! {
+        ::core::compile_error! { "expected string literal" }
+        struct S;
+    };
+    assert_eq!(tokens.to_string(), expected.to_string());
+}
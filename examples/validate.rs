@@ -0,0 +1,55 @@
+use attr_parser_fn::{
+    meta::{key_value, ParseMetaExt},
+    ParseArgs, ParseAttrTrait,
+};
+use syn::{parse_quote, Attribute, Error, LitInt, LitStr};
+
+fn main() {
+    let parser = ParseArgs::new().meta((
+        ("limit", key_value::<LitInt>())
+            .try_map(|lit| lit.base10_parse::<u8>())
+            .validate(|n| {
+                if *n == 0 {
+                    Err("`limit` must be positive")
+                } else {
+                    Ok(())
+                }
+            }),
+        ("name", key_value::<LitStr>()).try_map(|lit| {
+            if lit.value().is_empty() {
+                Err(Error::new(lit.span(), "`name` must not be empty"))
+            } else {
+                Ok(lit.value())
+            }
+        }),
+    ));
+
+    let attr: Attribute = parse_quote!(#[my_attr(limit = 3, name = "x")]);
+    let (limit, name) = parser.clone().parse_attr(&attr).unwrap().meta;
+    assert_eq!(limit, 3);
+    assert_eq!(name, "x");
+
+    let attr: Attribute = parse_quote!(#[my_attr(limit = 0, name = "x")]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    assert_eq!(err.to_string(), "`limit` must be positive");
+
+    // the error of a `try_map` closure is kept, with a note at the occurrence
+    let attr: Attribute = parse_quote!(#[my_attr(limit = 300, name = "x")]);
+    let err = parser.clone().parse_attr(&attr).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "number too large to fit in target type",
+            "for the value given here"
+        ]
+    );
+
+    let attr: Attribute = parse_quote!(#[my_attr(limit = 1, name = "")]);
+    let err = parser.parse_attr(&attr).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        ["`name` must not be empty", "for the value given here"]
+    );
+}
@@ -1,13 +1,17 @@
+use std::marker::PhantomData;
+
 use impl_variadics::impl_variadics;
 use syn::{
     parse::{Parse, ParseStream},
-    Result,
+    Error, Result,
 };
 
-use crate::with_comma;
+use crate::{meta::OneOf2, starts_meta_item, with_comma, Marker};
 
 pub trait ParseRequiredArgs {
     type Output;
+    const ARITY: Option<usize> = None;
+
     fn parse(input: ParseStream) -> Result<Self::Output>;
 }
 
@@ -18,6 +22,7 @@ impl_variadics! {
             #(#T0: Parse,)*
         {
             type Output = (#(#T0,)*);
+            const ARITY: Option<usize> = Some(#length);
 
             fn parse(_input: ParseStream) -> Result<Self::Output> {
                 let r = (#({
@@ -31,3 +36,108 @@ impl_variadics! {
         }
     }
 }
+
+pub type Either<A, B> = OneOf2<A, B>;
+
+pub trait RequiredArgsParser {
+    type Output;
+
+    fn parse(self, input: ParseStream) -> Result<Self::Output>;
+
+    fn arity(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<T> RequiredArgsParser for Marker<T>
+where
+    T: ParseRequiredArgs,
+{
+    type Output = T::Output;
+
+    fn parse(self, input: ParseStream) -> Result<Self::Output> {
+        T::parse(input)
+    }
+
+    fn arity(&self) -> Option<usize> {
+        T::ARITY
+    }
+}
+
+pub fn arg<T>(name: &str) -> Arg<'_, T>
+where
+    T: Parse,
+{
+    Arg {
+        name,
+        _marker: PhantomData,
+    }
+}
+
+pub struct Arg<'a, T> {
+    name: &'a str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Arg<'_, T> {
+    fn clone(&self) -> Self {
+        Arg {
+            name: self.name,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Arg<'_, T>
+where
+    T: Parse,
+{
+    fn parse(self, input: ParseStream, index: usize, total: usize) -> Result<T> {
+        let position = index + 1;
+        if input.is_empty() || starts_meta_item(input.cursor()) {
+            return Err(Error::new(
+                input.span(),
+                format!(
+                    "missing required positional argument `{}` ({position} of {total})",
+                    self.name
+                ),
+            ));
+        }
+
+        input.parse().map_err(|err| {
+            Error::new(
+                err.span(),
+                format!(
+                    "{err} for positional argument `{}` ({position} of {total})",
+                    self.name
+                ),
+            )
+        })
+    }
+}
+
+impl_variadics! {
+    1..21 "T*" => {
+        impl<'a, #(#T0,)*> RequiredArgsParser for (#(Arg<'a, #T0>,)*)
+        where
+            #(#T0: Parse,)*
+        {
+            type Output = (#(#T0,)*);
+
+            fn parse(self, input: ParseStream) -> Result<Self::Output> {
+                let total = #length;
+                let r = (#({
+                    let x = self.#index.parse(input, #index, total)?;
+                    with_comma(input)?;
+                    x
+                },)*);
+
+                Ok(r)
+            }
+
+            fn arity(&self) -> Option<usize> {
+                Some(#length)
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use syn::{Attribute, Error, Ident, Result};
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, Attribute, Error, Expr, ExprLit, Ident, Lit, Meta,
+    Path, Result, Token,
+};
 
 fn path_is<I>(expect: &I) -> impl Fn(&&Attribute) -> bool + '_
 where
@@ -51,3 +54,167 @@ where
     }
     Ok(found)
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct Cfgs {
+    enabled: Vec<(String, Option<String>)>,
+}
+
+impl Cfgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn flag(mut self, name: &str) -> Self {
+        self.enabled.push((name.to_string(), None));
+        self
+    }
+
+    pub fn key_value(mut self, key: &str, value: &str) -> Self {
+        self.enabled
+            .push((key.to_string(), Some(value.to_string())));
+        self
+    }
+
+    pub fn feature(self, name: &str) -> Self {
+        self.key_value("feature", name)
+    }
+
+    fn eval(&self, predicate: &Meta) -> Result<bool> {
+        match predicate {
+            Meta::Path(path) => {
+                let name = cfg_name(path)?;
+                Ok(self
+                    .enabled
+                    .iter()
+                    .any(|(key, value)| *key == name && value.is_none()))
+            }
+
+            Meta::NameValue(meta) => {
+                let name = cfg_name(&meta.path)?;
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) = &meta.value
+                else {
+                    return Err(Error::new_spanned(&meta.value, "expected string literal"));
+                };
+                let value = value.value();
+                Ok(self
+                    .enabled
+                    .iter()
+                    .any(|(key, given)| *key == name && given.as_deref() == Some(&*value)))
+            }
+
+            Meta::List(list) => {
+                let name = cfg_name(&list.path)?;
+                let predicates =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                // every predicate is evaluated, so that a malformed one is always reported
+                match &*name {
+                    "all" => predicates
+                        .iter()
+                        .try_fold(true, |all, p| Ok(self.eval(p)? && all)),
+                    "any" => predicates
+                        .iter()
+                        .try_fold(false, |any, p| Ok(self.eval(p)? || any)),
+                    "not" if predicates.len() == 1 => Ok(!self.eval(&predicates[0])?),
+                    "not" => Err(Error::new_spanned(
+                        list,
+                        "`not` expects exactly one predicate",
+                    )),
+                    _ => Err(Error::new_spanned(
+                        &list.path,
+                        format!("unknown cfg predicate `{name}`"),
+                    )),
+                }
+            }
+        }
+    }
+}
+
+fn cfg_name(path: &Path) -> Result<String> {
+    path.get_ident()
+        .map(Ident::to_string)
+        .ok_or_else(|| Error::new_spanned(path, "expected identifier"))
+}
+
+// Replaces every `#[cfg_attr(predicate, attrs..)]` with the attributes it wraps.
+// With `cfgs` given, attributes whose predicate does not hold are dropped.
+pub fn expand_cfg_attr(attrs: &[Attribute], cfgs: Option<&Cfgs>) -> Result<Vec<Attribute>> {
+    let mut expanded = vec![];
+    for attr in attrs {
+        expand_into(attr, cfgs, &mut expanded)?;
+    }
+    Ok(expanded)
+}
+
+fn expand_into(attr: &Attribute, cfgs: Option<&Cfgs>, expanded: &mut Vec<Attribute>) -> Result<()> {
+    if !attr.path().is_ident("cfg_attr") {
+        expanded.push(attr.clone());
+        return Ok(());
+    }
+
+    let (predicate, metas) = attr.parse_args_with(|input: ParseStream| {
+        let predicate = input.parse::<Meta>()?;
+        input.parse::<Token![,]>()?;
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        Ok((predicate, metas))
+    })?;
+
+    if let Some(cfgs) = cfgs {
+        if !cfgs.eval(&predicate)? {
+            return Ok(());
+        }
+    }
+
+    for meta in metas {
+        let inner = Attribute {
+            pound_token: attr.pound_token,
+            style: attr.style,
+            bracket_token: attr.bracket_token,
+            meta,
+        };
+        expand_into(&inner, cfgs, expanded)?;
+    }
+    Ok(())
+}
+
+pub fn all_expanded<I>(
+    attrs: &[Attribute],
+    expect_path: &I,
+    cfgs: Option<&Cfgs>,
+) -> Result<Vec<Attribute>>
+where
+    Ident: PartialEq<I>,
+    I: ?Sized,
+{
+    let mut expanded = expand_cfg_attr(attrs, cfgs)?;
+    expanded.retain(|attr| path_is(expect_path)(&attr));
+    Ok(expanded)
+}
+
+pub fn first_expanded<I>(
+    attrs: &[Attribute],
+    expect_path: &I,
+    cfgs: Option<&Cfgs>,
+) -> Result<Option<Attribute>>
+where
+    Ident: PartialEq<I>,
+    I: ?Sized,
+{
+    Ok(all_expanded(attrs, expect_path, cfgs)?.into_iter().next())
+}
+
+pub fn only_expanded<I>(
+    attrs: &[Attribute],
+    expect_path: &I,
+    cfgs: Option<&Cfgs>,
+) -> Result<Option<Attribute>>
+where
+    Ident: PartialEq<I>,
+    I: Display + ?Sized,
+{
+    let expanded = expand_cfg_attr(attrs, cfgs)?;
+    Ok(only(&expanded, expect_path)?.cloned())
+}
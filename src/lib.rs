@@ -1,120 +1,416 @@
-use std::{collections::HashSet, marker::PhantomData};
+#[cfg(feature = "proc-macro")]
+extern crate proc_macro;
 
-use args::ParseRequiredArgs;
-use meta::ParseMeta;
+use std::marker::PhantomData;
+
+use args::{ParseRequiredArgs, RequiredArgsParser};
+use meta::{path_to_string, Captured, CatchAll, KeyInfo, ParseMeta};
 use opt_args::ParseOptionalArgs;
-use proc_macro2::{TokenStream, TokenTree};
+use options::{ParseOptions, ParseWithOptions};
+use origins::Origins;
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
 use quote::ToTokens;
 use rest_args::ParseRestArgs;
 use syn::{
     buffer::Cursor,
-    parse::{ParseStream, Parser},
-    Attribute, Error, Meta, Result, Token,
+    ext::IdentExt,
+    parse::{discouraged::Speculative, Parse, ParseStream, Parser},
+    Attribute, Error, LitStr, Meta, Path, Result, Token,
 };
 
 pub mod args;
 pub mod find_attr;
 pub mod meta;
 pub mod opt_args;
+mod options;
+mod origins;
 pub mod rest_args;
 
+#[cfg(feature = "derive")]
+pub use attr_parser_fn_derive::ParseAttr;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use syn;
+}
+
 pub trait ParseAttrTrait: Sized {
     type Output;
     fn parse(self, input: ParseStream) -> Result<Self::Output>;
 
+    // Reports the meta keys this parser accepts, where it knows them.
+    fn visit_keys(&self, _f: &mut dyn FnMut(&KeyInfo)) {}
+
     fn parse_attr(self, input: &Attribute) -> Result<Self::Output> {
         (|input: ParseStream| self.parse(input)).parse2(match &input.meta {
             Meta::Path(_) => TokenStream::new(),
             Meta::List(list) => list.tokens.clone(),
-            Meta::NameValue(meta) => {
-                return Err(Error::new_spanned(
-                    meta,
-                    "expect `path` or `list(...)`, found `key = value`",
-                ))
-            }
+            Meta::NameValue(meta) => meta.value.to_token_stream(),
         })
     }
 
+    fn embedded_str(self) -> EmbeddedStr<Self> {
+        EmbeddedStr(self)
+    }
+
+    fn parse_tokens(self, tokens: TokenStream) -> Result<Self::Output> {
+        (|input: ParseStream| self.parse(input)).parse2(tokens)
+    }
+
+    #[cfg(feature = "proc-macro")]
+    fn parse_proc_macro_tokens(self, tokens: proc_macro::TokenStream) -> Result<Self::Output> {
+        self.parse_tokens(tokens.into())
+    }
+
+    fn parse_attribute_macro<I>(
+        self,
+        args: TokenStream,
+        item: TokenStream,
+    ) -> std::result::Result<(Self::Output, I), TokenStream>
+    where
+        I: Parse,
+    {
+        let parsed_args = self.parse_tokens(args);
+        let parsed_item = syn::parse2::<I>(item.clone());
+
+        let error = match (parsed_args, parsed_item) {
+            (Ok(args), Ok(item)) => return Ok((args, item)),
+            (Err(mut err), Err(item_err)) => {
+                err.combine(item_err);
+                err
+            }
+            (Err(err), _) | (_, Err(err)) => err,
+        };
+
+        // keep the item so that the errors are not buried under unresolved names
+        let mut tokens = error.to_compile_error();
+        tokens.extend(item);
+        Err(tokens)
+    }
+
+    #[cfg(feature = "proc-macro")]
+    fn parse_proc_macro_attribute<I>(
+        self,
+        args: proc_macro::TokenStream,
+        item: proc_macro::TokenStream,
+    ) -> std::result::Result<(Self::Output, I), proc_macro::TokenStream>
+    where
+        I: Parse,
+    {
+        self.parse_attribute_macro(args.into(), item.into())
+            .map_err(Into::into)
+    }
+
     fn parse_concat_attrs<'r, I>(self, input: I) -> Result<Self::Output>
     where
         I: Iterator<Item = &'r Attribute>,
     {
-        let parser = |input: ParseStream| self.parse(input);
-        let mut concatenated = TokenStream::new();
+        let (concatenated, _) = concat_attrs(input);
+        self.parse_tokens(concatenated)
+    }
+}
+
+pub trait ParseAttr: Sized {
+    fn parse(input: ParseStream) -> Result<Self>;
+
+    fn parser() -> Marker<Self> {
+        marker()
+    }
+
+    fn parse_attr(input: &Attribute) -> Result<Self> {
+        Self::parser().parse_attr(input)
+    }
 
+    fn parse_tokens(tokens: TokenStream) -> Result<Self> {
+        Self::parser().parse_tokens(tokens)
+    }
+
+    fn parse_concat_attrs<'r, I>(input: I) -> Result<Self>
+    where
+        I: Iterator<Item = &'r Attribute>,
+    {
+        Self::parser().parse_concat_attrs(input)
+    }
+}
+
+pub struct Marker<T>(PhantomData<T>);
+
+impl<T> Clone for Marker<T> {
+    fn clone(&self) -> Self {
+        marker()
+    }
+}
+
+#[derive(Clone)]
+pub struct AccumulateErrors<P>(P);
+
+#[derive(Clone)]
+pub struct Interleaved<P>(P);
+
+#[derive(Clone)]
+pub struct EmbeddedStr<P>(P);
+
+impl<P> ParseAttrTrait for EmbeddedStr<P>
+where
+    P: ParseAttrTrait,
+{
+    type Output = P::Output;
+
+    fn parse(self, input: ParseStream) -> Result<Self::Output> {
+        let lit = input.parse::<LitStr>()?;
+        if !input.is_empty() {
+            return Err(input.error("expected a single string literal"));
+        }
+
+        lit.parse_with(|input: ParseStream| self.0.parse(input))
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_keys(f)
+    }
+
+    // Each attribute holds its own string, so the contents are joined instead.
+    fn parse_concat_attrs<'r, I>(self, input: I) -> Result<Self::Output>
+    where
+        I: Iterator<Item = &'r Attribute>,
+    {
+        let mut joined = TokenStream::new();
         for attr in input {
-            let tokens = attr.meta.require_list()?.tokens.clone();
-            if tokens.is_empty() {
-                continue;
-            }
+            let tokens = match &attr.meta {
+                Meta::Path(_) => continue,
+                Meta::List(list) => list.tokens.clone(),
+                Meta::NameValue(meta) => meta.value.to_token_stream(),
+            };
 
-            let mut trail_comma = false;
-            concatenated.extend(tokens.into_iter().map(|token| {
-                trail_comma = matches!(&token, TokenTree::Punct(p) if p.as_char() == ',');
-                token
-            }));
+            let lit = (|input: ParseStream| {
+                let lit = input.parse::<LitStr>()?;
+                if !input.is_empty() {
+                    return Err(input.error("expected a single string literal"));
+                }
+                Ok(lit)
+            })
+            .parse2(tokens)?;
 
+            let contents = lit.parse::<TokenStream>()?;
+            let trail_comma = contents.is_empty()
+                || matches!(contents.clone().into_iter().last(), Some(TokenTree::Punct(p)) if p.as_char() == ',');
+            joined.extend(contents);
             if !trail_comma {
-                <Token![,]>::default().to_tokens(&mut concatenated);
+                <Token![,]>::default().to_tokens(&mut joined);
             }
         }
 
-        parser.parse2(concatenated)
+        self.0.parse_tokens(joined)
     }
 }
 
-pub struct Marker<T>(PhantomData<T>);
+impl<P> Interleaved<P> {
+    pub fn accumulate_errors(self) -> AccumulateErrors<Self> {
+        AccumulateErrors(self)
+    }
+}
+
+impl<P> AccumulateErrors<P> {
+    pub fn interleaved(self) -> Interleaved<Self> {
+        Interleaved(self)
+    }
+}
+
+impl<T> ParseAttrTrait for Marker<T>
+where
+    T: ParseAttr,
+{
+    type Output = T;
+
+    fn parse(self, input: ParseStream) -> Result<Self::Output> {
+        T::parse(input)
+    }
+}
+
+// `ParseArgs`, alone or wrapped by `accumulate_errors` and `interleaved` in any order.
+impl<P> ParseAttrTrait for P
+where
+    P: ParseWithOptions,
+{
+    type Output = P::Output;
+
+    fn parse(self, input: ParseStream) -> Result<Self::Output> {
+        self.parse_with(input, ParseOptions::default())
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.visit_meta_keys(f)
+    }
 
-impl<ReqArgs, OptArgs, RestArgs, Meta> ParseAttrTrait
-    for ParseArgs<Marker<ReqArgs>, Marker<OptArgs>, Marker<RestArgs>, Meta>
+    fn parse_concat_attrs<'r, I>(self, input: I) -> Result<Self::Output>
+    where
+        I: Iterator<Item = &'r Attribute>,
+    {
+        let (concatenated, origins) = concat_attrs(input);
+        let options = ParseOptions {
+            origins: Some(&origins),
+            ..ParseOptions::default()
+        };
+        (|input: ParseStream| self.parse_with(input, options)).parse2(concatenated)
+    }
+}
+
+impl<ReqArgs, OptArgs, RestArgs, Meta> ParseWithOptions
+    for ParseArgs<ReqArgs, Marker<OptArgs>, Marker<RestArgs>, Meta>
 where
-    ReqArgs: ParseRequiredArgs,
+    ReqArgs: RequiredArgsParser,
     OptArgs: ParseOptionalArgs,
     RestArgs: ParseRestArgs,
     Meta: ParseMeta,
 {
     type Output = ParseArgs<ReqArgs::Output, OptArgs::Output, RestArgs, Meta::Output>;
 
-    fn parse(mut self, input: ParseStream) -> Result<Self::Output> {
+    fn parse_with(self, input: ParseStream, options: ParseOptions) -> Result<Self::Output> {
+        self.parse_args(input, options)
+    }
+
+    fn visit_meta_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.meta.visit_keys(f)
+    }
+}
+
+impl<P> ParseWithOptions for AccumulateErrors<P>
+where
+    P: ParseWithOptions,
+{
+    type Output = P::Output;
+
+    fn parse_with(self, input: ParseStream, options: ParseOptions) -> Result<Self::Output> {
+        let options = ParseOptions {
+            accumulate: true,
+            ..options
+        };
+        self.0.parse_with(input, options)
+    }
+
+    fn visit_meta_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_meta_keys(f)
+    }
+}
+
+impl<P> ParseWithOptions for Interleaved<P>
+where
+    P: ParseWithOptions,
+{
+    type Output = P::Output;
+
+    fn parse_with(self, input: ParseStream, options: ParseOptions) -> Result<Self::Output> {
+        let options = ParseOptions {
+            interleaved: true,
+            ..options
+        };
+        self.0.parse_with(input, options)
+    }
+
+    fn visit_meta_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_meta_keys(f)
+    }
+}
+
+impl<ReqArgs, OptArgs, RestArgs, Meta> ParseArgs<ReqArgs, Marker<OptArgs>, Marker<RestArgs>, Meta>
+where
+    ReqArgs: RequiredArgsParser,
+    OptArgs: ParseOptionalArgs,
+    RestArgs: ParseRestArgs,
+    Meta: ParseMeta,
+{
+    fn parse_args(
+        self,
+        input: ParseStream,
+        options: ParseOptions,
+    ) -> Result<<Self as ParseWithOptions>::Output> {
+        let ParseOptions {
+            accumulate,
+            interleaved,
+            origins,
+        } = options;
+        let ParseArgs {
+            args: req_args,
+            meta,
+            ..
+        } = self;
+
+        let limit = req_args
+            .arity()
+            .zip(OptArgs::ARITY)
+            .map(|(req, opt)| req + opt);
+
+        let items = origins.map(|origins| origins.items(input.cursor()));
+
+        let positional = |input: ParseStream| -> Result<_> {
+            let begin = count_items(input.cursor());
+            let args = req_args.parse(input)?;
+            let (opt_args, mismatch) = OptArgs::parse_partial(input)?;
+            let stopped = input.cursor();
+            let rest_args = RestArgs::parse(input)?;
+            let taken = begin - count_items(input.cursor());
+            let by_position = take_positional_keys(input, &meta)?;
+            // rest arguments take any number of values, so there is no limit to exceed
+            if RestArgs::ARITY == Some(0) {
+                let mismatch = mismatch.filter(|_| input.cursor() == stopped);
+                check_arity(input, limit, mismatch)?;
+            }
+            Ok((args, opt_args, rest_args, by_position, taken))
+        };
+
+        // the meta parser sees the items in a new order, so their origins are reordered alike
+        let (args, opt_args, rest_args, (mut meta_items, given), origins) = if interleaved {
+            let (positional_items, rest_items, is_meta) = split_interleaved(input, &meta)?;
+            let (positional, leftover) =
+                (|input: ParseStream| Ok((positional(input)?, input.parse::<TokenStream>()?)))
+                    .parse2(positional_items)?;
+
+            // whatever the positional arguments did not take is reported by the meta parser
+            let (args, opt_args, rest_args, (mut meta_items, given), taken) = positional;
+            if !leftover.is_empty() {
+                meta_items.extend(leftover);
+                <Token![,]>::default().to_tokens(&mut meta_items);
+            }
+            meta_items.extend(rest_items);
+
+            let origins = items.map(|items| {
+                let (meta_origins, positional_origins): (Vec<_>, Vec<_>) = items
+                    .into_iter()
+                    .zip(is_meta)
+                    .partition(|(_, is_meta)| *is_meta);
+                positional_origins
+                    .into_iter()
+                    .skip(taken)
+                    .chain(meta_origins)
+                    .map(|(origin, _)| origin)
+                    .collect::<Vec<_>>()
+            });
+            (args, opt_args, rest_args, (meta_items, given), origins)
+        } else {
+            let (args, opt_args, rest_args, by_position, taken) = positional(input)?;
+            let origins = items.map(|items| items.into_iter().skip(taken).collect::<Vec<_>>());
+            (args, opt_args, rest_args, by_position, origins)
+        };
+
+        let origins = origins.as_deref();
+        let meta = if meta_items.is_empty() {
+            meta::parse_meta(meta, input, accumulate, &given, origins)?
+        } else {
+            meta_items.extend(input.parse::<TokenStream>()?);
+            (|input: ParseStream| meta::parse_meta(meta, input, accumulate, &given, origins))
+                .parse2(meta_items)?
+        };
+
         Ok(ParseArgs {
-            args: ReqArgs::parse(input)?,
-            opt_args: OptArgs::parse(input)?,
-            rest_args: RestArgs::parse(input)?,
-            meta: {
-                let mut specified_paths = HashSet::new();
-                let cursor = input.cursor();
-                syn::meta::parser(|nested| {
-                    let id = nested.path.require_ident()?.to_string();
-
-                    if specified_paths.contains(&id) {
-                        return Err(Error::new_spanned(
-                            nested.path,
-                            format!("path `{id}` has been specified",),
-                        ));
-                    }
-
-                    if !self.meta.parse(&nested)? {
-                        return Err(Error::new_spanned(
-                            nested.path,
-                            format!("attribute `{id}` is not expected, or the calling form is not compliant"),
-                        ));
-                    }
-
-                    specified_paths.insert(id);
-                    Ok(())
-                })
-                .parse2(cursor.token_stream())?;
-
-                // set input buffer to empty
-                input.step(|_| Ok(((), Cursor::empty()))).unwrap();
-
-                self.meta.finish()?
-            },
+            args,
+            opt_args,
+            rest_args,
+            meta,
         })
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ParseArgs<ReqArgs, OptArgs, RestArgs, Meta> {
     pub args: ReqArgs,
     pub opt_args: OptArgs,
@@ -133,6 +429,25 @@ impl ParseArgs<Marker<()>, Marker<()>, Marker<()>, ()> {
     }
 }
 
+impl Default for ParseArgs<Marker<()>, Marker<()>, Marker<()>, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Only a parser without a schema takes one, so that a schema is never replaced
+// after `catch_all` has wrapped it.
+impl<ReqArgs, OptArgs, RestArgs> ParseArgs<ReqArgs, OptArgs, RestArgs, ()> {
+    pub fn meta<T: ParseMeta>(self, meta: T) -> ParseArgs<ReqArgs, OptArgs, RestArgs, T> {
+        ParseArgs {
+            args: self.args,
+            opt_args: self.opt_args,
+            rest_args: self.rest_args,
+            meta,
+        }
+    }
+}
+
 impl<ReqArgs, OptArgs, RestArgs, Meta> ParseArgs<ReqArgs, OptArgs, RestArgs, Meta> {
     pub fn args<T: ParseRequiredArgs>(self) -> ParseArgs<Marker<T>, OptArgs, RestArgs, Meta> {
         ParseArgs {
@@ -143,6 +458,18 @@ impl<ReqArgs, OptArgs, RestArgs, Meta> ParseArgs<ReqArgs, OptArgs, RestArgs, Met
         }
     }
 
+    pub fn named_args<T: RequiredArgsParser>(
+        self,
+        args: T,
+    ) -> ParseArgs<T, OptArgs, RestArgs, Meta> {
+        ParseArgs {
+            args,
+            opt_args: self.opt_args,
+            rest_args: self.rest_args,
+            meta: self.meta,
+        }
+    }
+
     pub fn opt_args<T: ParseOptionalArgs>(self) -> ParseArgs<ReqArgs, Marker<T>, RestArgs, Meta> {
         ParseArgs {
             args: self.args,
@@ -161,14 +488,61 @@ impl<ReqArgs, OptArgs, RestArgs, Meta> ParseArgs<ReqArgs, OptArgs, RestArgs, Met
         }
     }
 
-    pub fn meta<T: ParseMeta>(self, meta: T) -> ParseArgs<ReqArgs, OptArgs, RestArgs, T> {
+    // Wraps the whole schema, so only keys no part of it accepts are captured.
+    pub fn catch_all<C>(self) -> ParseArgs<ReqArgs, OptArgs, RestArgs, CatchAll<Meta, C>>
+    where
+        Meta: ParseMeta,
+        C: Captured,
+    {
         ParseArgs {
             args: self.args,
             opt_args: self.opt_args,
             rest_args: self.rest_args,
-            meta,
+            meta: CatchAll::new(self.meta),
         }
     }
+
+    pub fn accumulate_errors(self) -> AccumulateErrors<Self> {
+        AccumulateErrors(self)
+    }
+
+    pub fn interleaved(self) -> Interleaved<Self> {
+        Interleaved(self)
+    }
+}
+
+fn concat_attrs<'r, I>(input: I) -> (TokenStream, Origins)
+where
+    I: Iterator<Item = &'r Attribute>,
+{
+    let mut concatenated = TokenStream::new();
+    let mut origins = Origins::new();
+
+    for attr in input {
+        let tokens = match &attr.meta {
+            Meta::Path(_) => continue,
+            Meta::List(list) => list.tokens.clone(),
+            Meta::NameValue(meta) => meta.value.to_token_stream(),
+        };
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let mut count = 0;
+        let mut trail_comma = false;
+        concatenated.extend(tokens.into_iter().inspect(|token| {
+            count += 1;
+            trail_comma = matches!(token, TokenTree::Punct(p) if p.as_char() == ',');
+        }));
+
+        if !trail_comma {
+            count += 1;
+            <Token![,]>::default().to_tokens(&mut concatenated);
+        }
+        origins.push(attr, count);
+    }
+
+    (concatenated, origins)
 }
 
 fn with_comma(input: ParseStream) -> Result<()> {
@@ -179,6 +553,250 @@ fn with_comma(input: ParseStream) -> Result<()> {
     Ok(())
 }
 
+// Parses `P` if the next positional slot holds one. Stops before meta items
+// such as `key = ...` or `key(...)` so they are left to the meta parser.
+// `Ok(None)` when no positional value is left, `Err` when the next value is not a `P`.
+// Either way nothing is consumed unless a value is returned.
+fn parse_positional<P>(input: ParseStream) -> Result<Option<P>>
+where
+    P: Parse,
+{
+    parse_positional_until(input, |input| input.peek(Token![,]))
+}
+
+fn parse_positional_until<P>(
+    input: ParseStream,
+    end: impl Fn(ParseStream) -> bool,
+) -> Result<Option<P>>
+where
+    P: Parse,
+{
+    if input.is_empty() || starts_meta_item(input.cursor()) {
+        return Ok(None);
+    }
+
+    let fork = input.fork();
+    let value = fork.parse()?;
+    if !fork.is_empty() && !end(&fork) {
+        return Err(fork.error("expected `,`"));
+    }
+
+    input.advance_to(&fork);
+    Ok(Some(value))
+}
+
+// Meta items always start with a path, so anything else left over after the
+// positional arguments is a positional value that nothing accepted. It is
+// either the optional argument that failed to parse, or one too many.
+fn check_arity(input: ParseStream, limit: Option<usize>, mismatch: Option<Error>) -> Result<()> {
+    let begin = input.cursor();
+    if begin.eof() || starts_path(begin) {
+        return Ok(());
+    }
+
+    if let Some(err) = mismatch {
+        return Err(err);
+    }
+
+    // without a known limit the meta parser reports the value
+    let Some(expected) = limit else {
+        return Ok(());
+    };
+
+    let mut extra = 0;
+    let mut tokens = TokenStream::new();
+    let mut cursor = begin;
+    while !cursor.eof() && !starts_path(cursor) {
+        extra += 1;
+        while let Some((token, next)) = cursor.token_tree() {
+            cursor = next;
+            if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                break;
+            }
+            tokens.extend([token]);
+        }
+    }
+
+    Err(Error::new_spanned(
+        tokens,
+        format!(
+            "too many positional arguments, expected {expected}, found {}",
+            expected + extra
+        ),
+    ))
+}
+
+// Splits the input into positional values and meta items, keeping the order
+// within each. Bare paths count as meta items only when the schema knows them.
+fn split_interleaved<M>(
+    input: ParseStream,
+    meta: &M,
+) -> Result<(TokenStream, TokenStream, Vec<bool>)>
+where
+    M: ParseMeta,
+{
+    let mut keys = vec![];
+    meta.visit_keys(&mut |info| keys.push(info.key.to_string()));
+
+    input.step(|cursor| {
+        let mut positional = TokenStream::new();
+        let mut meta_items = TokenStream::new();
+        let mut is_meta_item = vec![];
+        let mut rest = *cursor;
+
+        while !rest.eof() {
+            let is_meta =
+                starts_meta_item(rest) || bare_path(rest).is_some_and(|path| keys.contains(&path));
+
+            let mut item = TokenStream::new();
+            while let Some((token, next)) = rest.token_tree() {
+                rest = next;
+                let comma = matches!(&token, TokenTree::Punct(p) if p.as_char() == ',');
+                item.extend([token]);
+                if comma {
+                    break;
+                }
+            }
+
+            if is_meta {
+                meta_items.extend(item);
+            } else {
+                positional.extend(item);
+            }
+            is_meta_item.push(is_meta);
+        }
+
+        Ok(((positional, meta_items, is_meta_item), rest))
+    })
+}
+
+fn count_items(mut cursor: Cursor) -> usize {
+    let mut count = 0;
+    while !cursor.eof() {
+        count += 1;
+        while let Some((token, next)) = cursor.token_tree() {
+            cursor = next;
+            if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                break;
+            }
+        }
+    }
+    count
+}
+
+// Fills keys declared with `ParseMetaExt::positional` from the positional values
+// that follow the positional arguments, rewriting each value to `key = value`.
+fn take_positional_keys<M>(input: ParseStream, meta: &M) -> Result<(TokenStream, Vec<String>)>
+where
+    M: ParseMeta,
+{
+    let mut keys = vec![];
+    let mut positional = vec![];
+    let mut valueless = None;
+    meta.visit_keys(&mut |info| {
+        keys.push(info.key.trim().to_string());
+        if info.positional && !positional.iter().any(|key| key == info.key) {
+            positional.push(info.key.to_string());
+            if !info.forms.value {
+                valueless.get_or_insert_with(|| info.key.trim().to_string());
+            }
+        }
+    });
+
+    if let Some(key) = valueless {
+        return Err(input.error(format!(
+            "`{key}` takes no value, so it cannot be given positionally"
+        )));
+    }
+
+    let mut items = TokenStream::new();
+    let mut given = vec![];
+    for key in positional {
+        let cursor = input.cursor();
+        if cursor.eof()
+            || starts_meta_item(cursor)
+            || bare_path(cursor).is_some_and(|path| keys.contains(&path))
+        {
+            break;
+        }
+
+        let span = input.span();
+        let path = syn::parse_str::<Path>(key.trim())
+            .map_err(|_| Error::new(span, format!("positional key `{key}` is not a valid path")))?;
+        items.extend(path.to_token_stream().into_iter().map(|mut token| {
+            token.set_span(span);
+            token
+        }));
+        <Token![=]>::default().to_tokens(&mut items);
+
+        input.step(|cursor| {
+            let mut rest = *cursor;
+            while let Some((token, next)) = rest.token_tree() {
+                if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                    break;
+                }
+                items.extend([token]);
+                rest = next;
+            }
+            Ok(((), rest))
+        })?;
+        with_comma(input)?;
+
+        <Token![,]>::default().to_tokens(&mut items);
+        given.push(path_to_string(&path));
+    }
+
+    Ok((items, given))
+}
+
+// Returns the path if the item at `cursor` is nothing but a path.
+fn bare_path(mut cursor: Cursor) -> Option<String> {
+    let mut path = String::new();
+    loop {
+        let (ident, next) = cursor.ident()?;
+        path.push_str(&ident.unraw().to_string());
+        cursor = next;
+
+        match cursor.punct() {
+            None if cursor.eof() => return Some(path),
+            Some((comma, _)) if comma.as_char() == ',' => return Some(path),
+            Some((colon, next)) if colon.as_char() == ':' => match next.punct() {
+                Some((colon, next)) if colon.as_char() == ':' => {
+                    path.push_str("::");
+                    cursor = next;
+                }
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+}
+
+fn starts_path(cursor: Cursor) -> bool {
+    cursor.ident().is_some() || cursor.punct().is_some_and(|(p, _)| p.as_char() == ':')
+}
+
+fn starts_meta_item(mut cursor: Cursor) -> bool {
+    let mut path = false;
+    loop {
+        if let Some((_, next)) = cursor.ident() {
+            path = true;
+            cursor = next;
+        }
+
+        match cursor.punct() {
+            Some((colon, next)) if colon.as_char() == ':' && colon.spacing() == Spacing::Joint => {
+                match next.punct() {
+                    Some((colon, next)) if colon.as_char() == ':' => cursor = next,
+                    _ => return false,
+                }
+            }
+            Some((eq, _)) => return path && eq.as_char() == '=' && eq.spacing() == Spacing::Alone,
+            None => return path && cursor.group(Delimiter::Parenthesis).is_some(),
+        }
+    }
+}
+
 fn marker<T>() -> Marker<T> {
     Marker(PhantomData)
 }
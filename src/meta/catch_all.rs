@@ -0,0 +1,128 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{meta::ParseNestedMeta, Error, Meta, Result};
+
+use super::{declares_any, path_to_string, Forms, KeyInfo, Origin, ParseMeta};
+
+pub trait Captured: Sized {
+    fn capture(tokens: TokenStream) -> Result<Self>;
+}
+
+impl Captured for TokenStream {
+    fn capture(tokens: TokenStream) -> Result<Self> {
+        Ok(tokens)
+    }
+}
+
+impl Captured for Meta {
+    fn capture(tokens: TokenStream) -> Result<Self> {
+        syn::parse2(tokens)
+    }
+}
+
+pub struct CatchAll<T, C> {
+    parser: T,
+    captured: Vec<C>,
+    captured_keys: Vec<String>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<T, C> CatchAll<T, C> {
+    pub(crate) fn new(parser: T) -> Self {
+        CatchAll {
+            parser,
+            captured: vec![],
+            captured_keys: vec![],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Clone for CatchAll<T, C>
+where
+    T: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        CatchAll {
+            parser: self.parser.clone(),
+            captured: self.captured.clone(),
+            captured_keys: self.captured_keys.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, C> ParseMeta for CatchAll<T, C>
+where
+    T: ParseMeta,
+    C: Captured,
+{
+    type Output = (T::Output, Vec<C>);
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f);
+
+        // captured keys are passed through untouched, duplicates included
+        let forms = Forms::PATH.union(Forms::VALUE).union(Forms::LIST);
+        for key in &self.captured_keys {
+            f(&KeyInfo {
+                repeatable: true,
+                ..KeyInfo::new(key, forms)
+            });
+        }
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        if self.parser.parse(nested)? {
+            return Ok(true);
+        }
+
+        // a declared key in the wrong form is reported, not captured
+        let key = path_to_string(&nested.path);
+        if declares_any(&self.parser, std::slice::from_ref(&key)) {
+            return Ok(false);
+        }
+
+        let mut tokens = nested.path.to_token_stream();
+        nested.input.step(|cursor| {
+            let mut rest = *cursor;
+            while let Some((token, next)) = rest.token_tree() {
+                if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                    break;
+                }
+                tokens.extend([token]);
+                rest = next;
+            }
+            Ok(((), rest))
+        })?;
+
+        self.captured.push(C::capture(tokens)?);
+        if !self.captured_keys.contains(&key) {
+            self.captured_keys.push(key);
+        }
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        Ok((self.parser.finish()?, self.captured))
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        self.parser.finish_errors(failed)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.parser.ok_to_finish()
+    }
+}
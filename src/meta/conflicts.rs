@@ -1,8 +1,10 @@
 use impl_variadics::impl_variadics;
 use proc_macro2::Span;
-use syn::{meta::ParseNestedMeta, Error, Result};
+use syn::{meta::ParseNestedMeta, spanned::Spanned, Error, Result};
 
-use super::ParseMeta;
+use crate::origins::name_attributes;
+
+use super::{path_to_string, KeyInfo, Origin, ParseMeta};
 
 pub fn conflicts<T>(group: T) -> Conflicts<T>
 where
@@ -11,21 +13,30 @@ where
     Conflicts {
         parser: group,
         selected: None,
+        origin: None,
     }
 }
 
+#[derive(Clone)]
 pub struct Conflicts<T>
 where
     T: ConflictGroup,
 {
     parser: T,
-    selected: Option<(String, u8)>,
+    selected: Option<(String, Span, Option<Origin>, u8)>,
+    origin: Option<Origin>,
+}
+
+pub trait ParseConflicts: Sized {
+    fn conflicts() -> impl ParseMeta<Output = Self>;
 }
 
 pub trait ConflictGroup: Sized {
     type Output;
 
     fn parse_meta_conflict_alternative_arm(&self, f: &mut dyn std::fmt::Write) -> std::fmt::Result;
+    fn parse_meta_visit_keys(&self, f: &mut dyn FnMut(&KeyInfo));
+    fn parse_meta_set_origin(&mut self, _origin: Option<&Origin>) {}
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<Option<u8>>;
     fn finish(self, index: u8) -> Result<<Self as ConflictGroup>::Output>;
 }
@@ -42,6 +53,14 @@ impl_variadics! {
                 self.conflict_alternative_arm(f)
             }
 
+            fn parse_meta_visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+                self.visit_keys(f)
+            }
+
+            fn parse_meta_set_origin(&mut self, origin: Option<&Origin>) {
+                self.set_origin(origin)
+            }
+
             fn parse(&mut self, nested: &ParseNestedMeta) -> Result<Option<u8>> {
                 #(if self.#index.parse(nested)? {
                     Ok(Some(#index))
@@ -72,18 +91,39 @@ where
         write!(f, ")")
     }
 
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.parse_meta_visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.origin = origin.cloned();
+        self.parser.parse_meta_set_origin(origin)
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         match self.parser.parse(nested)? {
             Some(index) => {
-                let new_name = nested.path.require_ident()?.to_string();
+                let new_name = path_to_string(&nested.path);
                 match &self.selected {
-                    Some((name, _)) => Err(Error::new_spanned(
-                        &nested.path,
-                        format!("attribute `{new_name}` is conflicts with `{name}`"),
-                    )),
+                    Some((name, span, origin, _)) => {
+                        let mut message =
+                            format!("attribute `{new_name}` is conflicts with `{name}`");
+                        let mut note = format!("conflicting `{name}` given here");
+                        name_attributes(
+                            &mut message,
+                            &mut note,
+                            self.origin.as_ref(),
+                            origin.as_ref(),
+                        );
+
+                        let mut err = Error::new_spanned(&nested.path, message);
+                        err.combine(Error::new(*span, note));
+                        Err(err)
+                    }
 
                     None => {
-                        self.selected = Some((new_name, index));
+                        self.selected =
+                            Some((new_name, nested.path.span(), self.origin.clone(), index));
                         Ok(true)
                     }
                 }
@@ -95,7 +135,7 @@ where
 
     fn finish(self) -> Result<Self::Output> {
         match self.selected {
-            Some((_, index)) => self.parser.finish(index),
+            Some((_, _, _, index)) => self.parser.finish(index),
             None => Err(Error::new(Span::call_site(), {
                 let mut msg = "one of following attributes must be provided: ".to_string();
                 self.parser
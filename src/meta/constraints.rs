@@ -0,0 +1,280 @@
+use std::fmt::Write;
+
+use proc_macro2::{Delimiter, Span, TokenTree};
+use syn::{buffer::Cursor, ext::IdentExt, meta::ParseNestedMeta, spanned::Spanned, Error, Result};
+
+use super::{
+    diagnostics::unknown_key_help, parse::push_error, path_to_string, KeyInfo, Origin, ParseMeta,
+};
+
+#[derive(Clone)]
+enum Constraint {
+    Requires(String, String),
+    Excludes(String, String),
+    RequiredUnless(String, String),
+}
+
+#[derive(Clone)]
+pub struct Constrained<T> {
+    parser: T,
+    constraints: Vec<Constraint>,
+    given: Vec<(String, Span)>,
+    unknown: Vec<(String, String)>,
+}
+
+impl<T> Constrained<T> {
+    pub(crate) fn new(parser: T) -> Self {
+        Constrained {
+            parser,
+            constraints: vec![],
+            given: vec![],
+            unknown: vec![],
+        }
+    }
+
+    pub fn requires(mut self, key: &str, required: &str) -> Self
+    where
+        T: ParseMeta,
+    {
+        self.check_keys(&[key, required]);
+        self.constraints
+            .push(Constraint::Requires(key.to_string(), required.to_string()));
+        self
+    }
+
+    pub fn excludes(mut self, key: &str, excluded: &str) -> Self
+    where
+        T: ParseMeta,
+    {
+        self.check_keys(&[key, excluded]);
+        self.constraints
+            .push(Constraint::Excludes(key.to_string(), excluded.to_string()));
+        self
+    }
+
+    pub fn required_unless(mut self, key: &str, unless: &str) -> Self
+    where
+        T: ParseMeta,
+    {
+        self.check_keys(&[key, unless]);
+        self.constraints.push(Constraint::RequiredUnless(
+            key.to_string(),
+            unless.to_string(),
+        ));
+        self
+    }
+
+    // Nested lists are only looked into while their parser has not run yet,
+    // so the names are checked when the constraint is added.
+    fn check_keys(&mut self, names: &[&str])
+    where
+        T: ParseMeta,
+    {
+        let mut keys = vec![];
+        let mut opaque = vec![];
+        self.parser.visit_keys(&mut |info| {
+            let key = info.key.trim();
+            keys.push(key.to_string());
+            keys.extend(info.nested.iter().map(|inner| format!("{key}.{inner}")));
+            if info.forms.list && info.nested.is_empty() {
+                opaque.push(key.to_string());
+            }
+        });
+
+        // a parser that reports no keys cannot be checked
+        if keys.is_empty() {
+            return;
+        }
+
+        // nested keys of a list whose parser does not report them are taken on trust
+        let opens = |key: &String| {
+            opaque.contains(key)
+                || key.contains('.')
+                    && !keys
+                        .iter()
+                        .any(|inner| inner.starts_with(&format!("{key}.")))
+        };
+
+        for name in names {
+            let known = keys.iter().any(|key| {
+                name == key
+                    || opens(key)
+                        && name
+                            .strip_prefix(key.as_str())
+                            .is_some_and(|rest| rest.starts_with('.'))
+            });
+            if !known && !self.unknown.iter().any(|(unknown, _)| unknown == name) {
+                let mut message = format!("constraint refers to unknown key `{name}`");
+                if let Some(help) = unknown_key_help(&keys, name) {
+                    message.push('\n');
+                    message.push_str(&help);
+                }
+                self.unknown.push((name.to_string(), message));
+            }
+        }
+    }
+
+    fn given(&self, key: &str) -> Option<Span> {
+        self.given
+            .iter()
+            .find(|(given, _)| given == key)
+            .map(|(_, span)| *span)
+    }
+
+    fn check(&self) -> Option<Error> {
+        let mut errors = None;
+        for (_, message) in &self.unknown {
+            push_error(&mut errors, Error::new(Span::call_site(), message));
+        }
+
+        for constraint in &self.constraints {
+            match constraint {
+                Constraint::Requires(key, required) => {
+                    if let (Some(span), None) = (self.given(key), self.given(required)) {
+                        push_error(
+                            &mut errors,
+                            Error::new(span, format!("`{key}` requires `{required}`")),
+                        );
+                    }
+                }
+
+                Constraint::Excludes(key, excluded) => {
+                    if let (Some(key_span), Some(excluded_span)) =
+                        (self.given(key), self.given(excluded))
+                    {
+                        push_error(
+                            &mut errors,
+                            Error::new(
+                                key_span,
+                                format!("`{key}` cannot be used together with `{excluded}`"),
+                            ),
+                        );
+                        push_error(
+                            &mut errors,
+                            Error::new(excluded_span, format!("`{excluded}` is given here")),
+                        );
+                    }
+                }
+
+                Constraint::RequiredUnless(key, unless) => {
+                    if self.given(key).is_none() && self.given(unless).is_none() {
+                        push_error(
+                            &mut errors,
+                            Error::new(
+                                Span::call_site(),
+                                format!("`{key}` must be specified unless `{unless}` is given"),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+impl<T> ParseMeta for Constrained<T>
+where
+    T: ParseMeta,
+{
+    type Output = T::Output;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let begin = nested.input.cursor();
+        if !self.parser.parse(nested)? {
+            return Ok(false);
+        }
+
+        let key = path_to_string(&nested.path);
+        if let Some((content, _, _)) = begin.group(Delimiter::Parenthesis) {
+            scan_keys(content, &key, &mut self.given);
+        }
+        self.given.push((key, nested.path.span()));
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        let mut errors = self.check();
+        match self.parser.finish() {
+            Ok(output) => match errors {
+                Some(errors) => Err(errors),
+                None => Ok(output),
+            },
+            Err(err) => {
+                push_error(&mut errors, err);
+                Err(errors.unwrap())
+            }
+        }
+    }
+
+    fn finish_errors(mut self, failed: &[String]) -> Option<Error> {
+        // keys that failed to parse were still given
+        self.given
+            .extend(failed.iter().map(|key| (key.clone(), Span::call_site())));
+        let mut errors = self.check();
+        if let Some(err) = self.parser.finish_errors(failed) {
+            push_error(&mut errors, err);
+        }
+        errors
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.parser.ok_to_finish()
+    }
+}
+
+// Records the keys given inside a nested list as `outer.inner` without consuming them.
+fn scan_keys(mut cursor: Cursor, prefix: &str, given: &mut Vec<(String, Span)>) {
+    while !cursor.eof() {
+        let mut path = String::new();
+        let mut span = None;
+
+        loop {
+            if let Some((ident, next)) = cursor.ident() {
+                span.get_or_insert(ident.span());
+                path.push_str(&ident.unraw().to_string());
+                cursor = next;
+                continue;
+            }
+
+            match cursor.punct() {
+                Some((colon, next)) if colon.as_char() == ':' => match next.punct() {
+                    Some((colon, next)) if colon.as_char() == ':' => {
+                        path.push_str("::");
+                        cursor = next;
+                    }
+                    _ => break,
+                },
+                _ => break,
+            }
+        }
+
+        if let Some(span) = span {
+            let key = format!("{prefix}.{path}");
+            if let Some((content, _, _)) = cursor.group(Delimiter::Parenthesis) {
+                scan_keys(content, &key, given);
+            }
+            given.push((key, span));
+        }
+
+        while let Some((token, next)) = cursor.token_tree() {
+            cursor = next;
+            if matches!(token, TokenTree::Punct(p) if p.as_char() == ',') {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{buffer::Cursor, meta::ParseNestedMeta, token::Paren, Error, Token};
+
+use super::{Forms, ParseMeta};
+
+pub(crate) fn not_accepted_error<M>(meta: &M, nested: &ParseNestedMeta, id: &str) -> Error
+where
+    M: ParseMeta,
+{
+    let mut keys: Vec<String> = vec![];
+    let mut accepted: Option<Forms> = None;
+    meta.visit_keys(&mut |info| {
+        if info.key == id {
+            accepted = Some(accepted.unwrap_or_default().union(info.forms));
+        } else if !keys.iter().any(|key| key == info.key) {
+            keys.push(info.key.to_string());
+        }
+    });
+
+    match accepted {
+        Some(forms) => form_mismatch_error(nested, id, forms),
+        None => {
+            let mut message = format!("attribute `{id}` is not expected");
+            if let Some(help) = unknown_key_help(&keys, id) {
+                message.push('\n');
+                message.push_str(&help);
+            }
+            Error::new_spanned(&nested.path, message)
+        }
+    }
+}
+
+fn form_mismatch_error(nested: &ParseNestedMeta, id: &str, accepted: Forms) -> Error {
+    let input = nested.input;
+    let found = if input.is_empty() || input.peek(Token![,]) {
+        Some(Forms::PATH)
+    } else if input.peek(Token![=]) {
+        Some(Forms::VALUE)
+    } else if input.peek(Paren) {
+        Some(Forms::LIST)
+    } else {
+        None
+    };
+
+    let rest = rest_of_nested(input.cursor());
+    // parsers that do not report their forms have nothing to describe
+    let expected = describe_forms(id, accepted);
+    let given = found.and_then(|found| describe_forms(id, found));
+    let message = match (found, expected, given) {
+        (Some(found), ..) if accepted == Forms::PATH && found != Forms::PATH => {
+            format!("`{id}` is a flag and takes no value")
+        }
+        (Some(found), Some(expected), Some(given)) if accepted.union(found) != accepted => {
+            format!("`{id}` expects {expected}, found {given}")
+        }
+        _ => format!("the calling form of attribute `{id}` is not compliant"),
+    };
+
+    if rest.is_empty() {
+        Error::new_spanned(&nested.path, message)
+    } else {
+        Error::new_spanned(rest, message)
+    }
+}
+
+fn describe_forms(id: &str, forms: Forms) -> Option<String> {
+    let mut described = vec![];
+    if forms.path {
+        described.push(format!("`{id}`"));
+    }
+    if forms.value {
+        described.push(format!("`{id} = ...`"));
+    }
+    if forms.list {
+        described.push(format!("`{id}(...)`"));
+    }
+    (!described.is_empty()).then(|| described.join(" or "))
+}
+
+fn rest_of_nested(mut cursor: Cursor) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    while let Some((token, next)) = cursor.token_tree() {
+        if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+            break;
+        }
+        tokens.extend([token]);
+        cursor = next;
+    }
+    tokens
+}
+
+pub(crate) fn unknown_key_help(keys: &[String], id: &str) -> Option<String> {
+    if keys.is_empty() {
+        return None;
+    }
+
+    let max_distance = (id.chars().count() / 3).max(1);
+    let closest = keys
+        .iter()
+        .map(|key| (edit_distance(id, key), key))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance);
+
+    let mut help = "help: ".to_string();
+    match closest {
+        Some((_, key)) => write!(help, "did you mean `{key}`?").unwrap(),
+        None => {
+            help.push_str("valid attributes are ");
+            for (index, key) in keys.iter().enumerate() {
+                if index != 0 {
+                    help.push_str(", ");
+                }
+                write!(help, "`{key}`").unwrap();
+            }
+        }
+    }
+
+    Some(help)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
@@ -0,0 +1,173 @@
+use impl_variadics::impl_variadics;
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, Error, Result};
+
+use super::{path_to_string, KeyInfo, Origin, ParseMeta};
+
+pub fn any_of<T>(group: T) -> AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    AnyOf {
+        parser: group,
+        given: vec![],
+        min: 0,
+        max: None,
+    }
+}
+
+pub fn at_least_one<T>(group: T) -> AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    AnyOf {
+        min: 1,
+        ..any_of(group)
+    }
+}
+
+pub fn at_most<T>(group: T, max: usize) -> AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    AnyOf {
+        max: Some(max),
+        ..any_of(group)
+    }
+}
+
+#[derive(Clone)]
+pub struct AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    parser: T,
+    given: Vec<u8>,
+    min: usize,
+    max: Option<usize>,
+}
+
+pub trait AnyOfGroup: Sized {
+    type Output;
+
+    fn parse_meta_conflict_alternative_arm(&self, f: &mut dyn std::fmt::Write) -> std::fmt::Result;
+    fn parse_meta_visit_keys(&self, f: &mut dyn FnMut(&KeyInfo));
+    fn parse_meta_set_origin(&mut self, _origin: Option<&Origin>) {}
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<Option<u8>>;
+    fn finish(self, given: &[u8]) -> Result<<Self as AnyOfGroup>::Output>;
+}
+
+impl_variadics! {
+    1..21 "T*" => {
+        impl<#(#T0,)*> AnyOfGroup for (#(#T0,)*)
+        where
+            #(#T0: ParseMeta,)*
+        {
+            type Output = (#(Option<#T0::Output>,)*);
+
+            fn parse_meta_conflict_alternative_arm(&self, f: &mut dyn std::fmt::Write) -> std::fmt::Result {
+                self.conflict_alternative_arm(f)
+            }
+
+            fn parse_meta_visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+                self.visit_keys(f)
+            }
+
+            fn parse_meta_set_origin(&mut self, origin: Option<&Origin>) {
+                self.set_origin(origin)
+            }
+
+            fn parse(&mut self, nested: &ParseNestedMeta) -> Result<Option<u8>> {
+                #(if self.#index.parse(nested)? {
+                    return Ok(Some(#index));
+                })*
+
+                Ok(None)
+            }
+
+            fn finish(self, given: &[u8]) -> Result<<Self as AnyOfGroup>::Output> {
+                Ok((#(
+                    if given.contains(&#index) {
+                        Some(self.#index.finish()?)
+                    } else {
+                        None
+                    },
+                )*))
+            }
+        }
+    }
+}
+
+impl<T> AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    fn alternatives(&self) -> String {
+        let mut alternatives = String::new();
+        self.parser
+            .parse_meta_conflict_alternative_arm(&mut alternatives)
+            .unwrap();
+        alternatives
+    }
+}
+
+impl<T> ParseMeta for AnyOf<T>
+where
+    T: AnyOfGroup,
+{
+    type Output = T::Output;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(f, "(any of: ")?;
+        self.parser.parse_meta_conflict_alternative_arm(f)?;
+        write!(f, ")")
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.parse_meta_visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.parse_meta_set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let Some(index) = self.parser.parse(nested)? else {
+            return Ok(false);
+        };
+
+        if let Some(max) = self.max {
+            if self.given.len() >= max {
+                return Err(Error::new_spanned(
+                    &nested.path,
+                    format!(
+                        "attribute `{}` is one too many, at most {max} of following attributes may be provided: {}",
+                        path_to_string(&nested.path),
+                        self.alternatives()
+                    ),
+                ));
+            }
+        }
+
+        self.given.push(index);
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        if self.given.len() < self.min {
+            return Err(Error::new(
+                Span::call_site(),
+                format!(
+                    "at least one of following attributes must be provided: {}",
+                    self.alternatives()
+                ),
+            ));
+        }
+
+        self.parser.finish(&self.given)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.given.len() >= self.min
+    }
+}
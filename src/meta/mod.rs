@@ -1,42 +1,146 @@
 use std::fmt::Write;
 
 use impl_variadics::impl_variadics;
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 use syn::{
+    buffer::Cursor,
+    ext::IdentExt,
     meta::ParseNestedMeta,
     parenthesized,
     parse::{Parse, ParseStream, Parser},
     token::Paren,
-    Error, LitStr, Result, Token,
+    Error, LitBool, LitStr, Path, Result, Token,
 };
 
 use crate::ParseAttrTrait;
 
 pub use self::{
-    conflicts::{conflicts, ConflictGroup},
-    utils::{meta_list, Map, MetaList, Optional, ParseMetaExt},
+    catch_all::{Captured, CatchAll},
+    conflicts::{conflicts, ConflictGroup, Conflicts, ParseConflicts},
+    constraints::Constrained,
+    groups::{any_of, at_least_one, at_most, AnyOf, AnyOfGroup},
+    one_of::{
+        one_of, OneOf10, OneOf11, OneOf12, OneOf13, OneOf14, OneOf15, OneOf16, OneOf17, OneOf18,
+        OneOf19, OneOf2, OneOf20, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8, OneOf9,
+        OneOfArms,
+    },
+    repeated::Repeated,
+    spanned::{Spanned, WithSpan},
+    utils::{meta_list, DefaultWith, Map, MetaList, Optional, ParseMetaExt, Positional},
+    validate::{TryMap, Validate},
 };
+pub use crate::origins::Origin;
 
+pub(crate) use self::parse::parse_meta;
+use self::parse::push_error;
+
+mod catch_all;
 mod conflicts;
+mod constraints;
+mod diagnostics;
+mod groups;
+mod one_of;
+mod parse;
+mod repeated;
+mod spanned;
 mod utils;
+mod validate;
 
 pub trait ParseMeta {
     type Output;
 
     fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result;
+    fn visit_keys(&self, _f: &mut dyn FnMut(&KeyInfo)) {}
+    // Called before each item with the attribute it came from, when known.
+    fn set_origin(&mut self, _origin: Option<&Origin>) {}
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool>;
     fn finish(self) -> Result<Self::Output>;
     fn ok_to_finish(&self) -> bool;
+
+    // Called instead of `finish` when collecting errors after some keys have
+    // already failed to parse, so that those keys are not also reported missing.
+    fn finish_errors(self, failed: &[String]) -> Option<Error>
+    where
+        Self: Sized,
+    {
+        if declares_any(&self, failed) {
+            None
+        } else {
+            self.finish().err()
+        }
+    }
 }
 
 pub trait ParseMetaUnnamed {
     type Output;
 
+    fn forms(&self) -> Forms {
+        Forms::default()
+    }
+    fn visit_nested_keys(&self, _f: &mut dyn FnMut(&KeyInfo)) {}
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool>;
     fn finish(self) -> Option<Self::Output>;
     fn ok_to_finish(&self) -> bool;
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Forms {
+    pub path: bool,
+    pub value: bool,
+    pub list: bool,
+}
+
+impl Forms {
+    pub const PATH: Self = Forms {
+        path: true,
+        value: false,
+        list: false,
+    };
+
+    pub const VALUE: Self = Forms {
+        path: false,
+        value: true,
+        list: false,
+    };
+
+    pub const LIST: Self = Forms {
+        path: false,
+        value: false,
+        list: true,
+    };
+
+    pub fn union(self, other: Self) -> Self {
+        Forms {
+            path: self.path || other.path,
+            value: self.value || other.value,
+            list: self.list || other.list,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct KeyInfo<'a> {
+    pub key: &'a str,
+    pub forms: Forms,
+    pub repeatable: bool,
+    pub positional: bool,
+    // keys accepted inside a nested list, written as `inner` or `inner.deeper`
+    pub nested: &'a [String],
+}
+
+impl<'a> KeyInfo<'a> {
+    pub fn new(key: &'a str, forms: Forms) -> Self {
+        KeyInfo {
+            key,
+            forms,
+            repeatable: false,
+            positional: false,
+            nested: &[],
+        }
+    }
+}
+
 impl<T> ParseMeta for (&str, T)
 where
     T: ParseMetaUnnamed,
@@ -47,8 +151,24 @@ where
         write!(f, "`{}`", self.0)
     }
 
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        let mut nested = vec![];
+        self.1.visit_nested_keys(&mut |info| {
+            nested.push(info.key.to_string());
+            nested.extend(
+                info.nested
+                    .iter()
+                    .map(|inner| format!("{}.{inner}", info.key)),
+            );
+        });
+        f(&KeyInfo {
+            nested: &nested,
+            ..KeyInfo::new(self.0, self.1.forms())
+        })
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
-        if nested.path.is_ident(self.0) {
+        if path_matches(&nested.path, self.0) {
             self.1.parse(nested)
         } else {
             Ok(false)
@@ -69,10 +189,59 @@ where
     }
 }
 
+pub(crate) fn path_to_string(path: &Path) -> String {
+    let mut output = String::new();
+    if path.leading_colon.is_some() {
+        output.push_str("::");
+    }
+
+    for (index, segment) in path.segments.iter().enumerate() {
+        if index != 0 {
+            output.push_str("::");
+        }
+        output.push_str(&segment.ident.unraw().to_string());
+    }
+
+    output
+}
+
+fn path_matches(path: &Path, key: &str) -> bool {
+    let key = key.trim();
+    let (leading_colon, key) = match key.strip_prefix("::") {
+        Some(stripped) => (true, stripped),
+        None => (false, key),
+    };
+
+    if leading_colon != path.leading_colon.is_some() {
+        return false;
+    }
+
+    let mut segments = path.segments.iter();
+    for expect in key.split("::") {
+        match segments.next() {
+            Some(segment)
+                if segment.arguments.is_none() && segment.ident.unraw() == expect.trim() => {}
+            _ => return false,
+        }
+    }
+
+    segments.next().is_none()
+}
+
+fn declares_any<M>(meta: &M, keys: &[String]) -> bool
+where
+    M: ParseMeta,
+{
+    let mut found = false;
+    meta.visit_keys(&mut |info| found |= keys.iter().any(|key| key == info.key));
+    found
+}
+
 pub fn path_only() -> PathOnly {
     PathOnly { assigned: false }
 }
 
+#[derive(Clone)]
 pub struct PathOnly {
     assigned: bool,
 }
@@ -80,6 +249,10 @@ pub struct PathOnly {
 impl ParseMetaUnnamed for PathOnly {
     type Output = bool;
 
+    fn forms(&self) -> Forms {
+        Forms::PATH
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         if nested.input.peek(Token![,]) || nested.input.is_empty() {
             self.assigned = true;
@@ -98,6 +271,82 @@ impl ParseMetaUnnamed for PathOnly {
     }
 }
 
+pub fn flag() -> Flag {
+    Flag {
+        value: None,
+        allow_list: false,
+    }
+}
+
+#[derive(Clone)]
+pub struct Flag {
+    value: Option<bool>,
+    allow_list: bool,
+}
+
+impl Flag {
+    pub fn allow_list(mut self) -> Self {
+        self.allow_list = true;
+        self
+    }
+}
+
+impl ParseMetaUnnamed for Flag {
+    type Output = bool;
+
+    fn forms(&self) -> Forms {
+        let forms = Forms::PATH.union(Forms::VALUE);
+        if self.allow_list {
+            forms.union(Forms::LIST)
+        } else {
+            forms
+        }
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let value = if nested.input.peek(Token![,]) || nested.input.is_empty() {
+            true
+        } else if nested.input.peek(Token![=]) {
+            parse_bool(nested.value()?)?
+        } else if self.allow_list && nested.input.peek(Paren) {
+            let content;
+            parenthesized!(content in nested.input);
+            let value = parse_bool(&content)?;
+            if !content.is_empty() {
+                return Err(content.error("expected `true` or `false` only"));
+            }
+            value
+        } else {
+            return Ok(false);
+        };
+
+        self.value = Some(value);
+        Ok(true)
+    }
+
+    fn finish(self) -> Option<Self::Output> {
+        Some(self.value.unwrap_or(false))
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        true
+    }
+}
+
+fn parse_bool(input: ParseStream) -> Result<bool> {
+    if input.peek(LitBool) {
+        return Ok(input.parse::<LitBool>()?.value);
+    }
+
+    match input.parse::<TokenTree>() {
+        Ok(token) => Err(Error::new_spanned(
+            &token,
+            format!("expected `true` or `false`, found `{token}`"),
+        )),
+        Err(_) => Err(input.error("expected `true` or `false`")),
+    }
+}
+
 pub fn key_value<T>() -> KeyValue<T>
 where
     T: Parse,
@@ -105,6 +354,7 @@ where
     KeyValue { value: None }
 }
 
+#[derive(Clone)]
 pub struct KeyValue<T> {
     value: Option<T>,
 }
@@ -115,6 +365,10 @@ where
 {
     type Output = T;
 
+    fn forms(&self) -> Forms {
+        Forms::VALUE
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         if nested.input.peek(Token![=]) {
             self.value = Some(nested.value()?.parse::<T>()?);
@@ -140,6 +394,7 @@ where
     KeyStr { value: None }
 }
 
+#[derive(Clone)]
 pub struct KeyStr<T> {
     value: Option<T>,
 }
@@ -150,6 +405,10 @@ where
 {
     type Output = T;
 
+    fn forms(&self) -> Forms {
+        Forms::VALUE
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         if nested.input.peek(Token![=]) {
             let litstr: LitStr = nested.value()?.parse()?;
@@ -189,12 +448,36 @@ pub struct List<P>(ListInner<P>)
 where
     P: ParseAttrTrait;
 
+impl<P> Clone for List<P>
+where
+    P: ParseAttrTrait + Clone,
+    P::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        List(match &self.0 {
+            ListInner::Unassigned(parser) => ListInner::Unassigned(parser.clone()),
+            ListInner::Assigned(output) => ListInner::Assigned(output.clone()),
+            ListInner::Intermediate => ListInner::Intermediate,
+        })
+    }
+}
+
 impl<P> ParseMetaUnnamed for List<P>
 where
     P: ParseAttrTrait,
 {
     type Output = P::Output;
 
+    fn forms(&self) -> Forms {
+        Forms::LIST
+    }
+
+    fn visit_nested_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        if let ListInner::Unassigned(parser) = &self.0 {
+            parser.visit_keys(f)
+        }
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         if !nested.input.peek(Paren) {
             return Ok(false);
@@ -210,14 +493,24 @@ where
             unreachable!("cannot assign a list twice");
         };
 
-        *inner = ListInner::Assigned(parser.parse(&content)?);
-        Ok(true)
+        match parser.parse(&content) {
+            Ok(output) => {
+                *inner = ListInner::Assigned(output);
+                Ok(true)
+            }
+            Err(err) => {
+                // discard the rest of the list so that it will not be reported again
+                content.step(|_| Ok(((), Cursor::empty()))).unwrap();
+                Err(err)
+            }
+        }
     }
 
     fn finish(self) -> Option<Self::Output> {
         match self.0 {
             ListInner::Assigned(output) => Some(output),
-            ListInner::Intermediate => unreachable!("this list is not correctly assigned"),
+            // the list failed to parse and the error has been reported
+            ListInner::Intermediate => None,
             ListInner::Unassigned(parser) => {
                 let new_parser = |input: ParseStream| parser.parse(input);
                 new_parser.parse2(TokenStream::new()).ok()
@@ -250,18 +543,50 @@ impl_variadics! {
                 Ok(())
             }
 
+            fn visit_keys(&self, _f: &mut dyn FnMut(&KeyInfo)) {
+                #(self.#index.visit_keys(_f);)*
+            }
+
+            fn set_origin(&mut self, _origin: Option<&Origin>) {
+                #(self.#index.set_origin(_origin);)*
+            }
+
             fn parse(&mut self, _nested: &ParseNestedMeta) -> Result<bool> {
-                Ok(false #(|| self.#index.parse(_nested)?)*)
+                #(if self.#index.parse(_nested)? {
+                    return Ok(true);
+                })*
+
+                Ok(false)
             }
 
             fn finish(self) -> Result<Self::Output> {
-                Ok((
-                    #(self.#index.finish()?,)*
-                ))
+                #[allow(unused_mut)]
+                let mut _errors: Option<Error> = None;
+                let _output = (#(
+                    self.#index.finish().map_err(|err| push_error(&mut _errors, err)),
+                )*);
+
+                match _errors {
+                    Some(errors) => Err(errors),
+                    None => Ok((#(_output.#index.ok().unwrap(),)*)),
+                }
+            }
+
+            fn finish_errors(self, _failed: &[String]) -> Option<Error> {
+                #[allow(unused_mut)]
+                let mut _errors: Option<Error> = None;
+                #(if let Some(err) = self.#index.finish_errors(_failed) {
+                    push_error(&mut _errors, err);
+                })*
+                _errors
             }
 
             fn ok_to_finish(&self) -> bool {
-                true #(&& self.#index.ok_to_finish())*
+                #(if !self.#index.ok_to_finish() {
+                    return false;
+                })*
+
+                true
             }
         }
     }
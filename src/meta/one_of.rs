@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    meta::ParseNestedMeta,
+    parse::{discouraged::Speculative, Parse, ParseStream},
+    Result,
+};
+
+use super::{conflicts, ConflictGroup, Conflicts, KeyInfo, Origin, ParseMeta};
+
+pub fn one_of<T>(group: T) -> Conflicts<OneOfArms<T>>
+where
+    OneOfArms<T>: ConflictGroup,
+{
+    conflicts(OneOfArms(group))
+}
+
+#[derive(Clone)]
+pub struct OneOfArms<T>(T);
+
+macro_rules! one_of_group {
+    ($name:ident: $($variant:ident $index:tt),*) => {
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum $name<$($variant),*> {
+            $($variant($variant),)*
+        }
+
+        impl<$($variant,)*> ConflictGroup for OneOfArms<($($variant,)*)>
+        where
+            $($variant: ParseMeta,)*
+        {
+            type Output = $name<$($variant::Output),*>;
+
+            fn parse_meta_conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+                self.0.conflict_alternative_arm(f)
+            }
+
+            fn parse_meta_visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+                self.0.visit_keys(f)
+            }
+
+            fn parse_meta_set_origin(&mut self, origin: Option<&Origin>) {
+                self.0.set_origin(origin)
+            }
+
+            fn parse(&mut self, nested: &ParseNestedMeta) -> Result<Option<u8>> {
+                $(if (self.0).$index.parse(nested)? {
+                    return Ok(Some($index));
+                })*
+
+                Ok(None)
+            }
+
+            fn finish(self, index: u8) -> Result<<Self as ConflictGroup>::Output> {
+                match index {
+                    $($index => (self.0).$index.finish().map($name::$variant),)*
+                    _ => unreachable!("invalid index"),
+                }
+            }
+        }
+
+        impl<$($variant,)*> Parse for $name<$($variant),*>
+        where
+            $($variant: Parse,)*
+        {
+            fn parse(input: ParseStream) -> Result<Self> {
+                let mut expected = vec![];
+
+                $(
+                    let fork = input.fork();
+                    match fork.parse() {
+                        Ok(value) => {
+                            input.advance_to(&fork);
+                            return Ok($name::$variant(value));
+                        }
+                        Err(err) => expected.push(err.to_string()),
+                    }
+                )*
+
+                Err(input.error(expected_message(expected)))
+            }
+        }
+
+        impl<$($variant,)*> ToTokens for $name<$($variant),*>
+        where
+            $($variant: ToTokens,)*
+        {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                match self {
+                    $($name::$variant(value) => value.to_tokens(tokens),)*
+                }
+            }
+        }
+    };
+}
+
+fn expected_message(errors: Vec<String>) -> String {
+    let mut expected: Vec<String> = vec![];
+    for err in errors {
+        let err = match err.strip_prefix("expected ") {
+            Some(stripped) => stripped.to_string(),
+            None => err,
+        };
+        if !expected.contains(&err) {
+            expected.push(err);
+        }
+    }
+
+    match expected.split_last() {
+        Some((last, [])) => format!("expected {last}"),
+        Some((last, rest)) => format!("expected {} or {last}", rest.join(", ")),
+        None => "unexpected token".to_string(),
+    }
+}
+
+one_of_group!(OneOf2: A 0, B 1);
+one_of_group!(OneOf3: A 0, B 1, C 2);
+one_of_group!(OneOf4: A 0, B 1, C 2, D 3);
+one_of_group!(OneOf5: A 0, B 1, C 2, D 3, E 4);
+one_of_group!(OneOf6: A 0, B 1, C 2, D 3, E 4, F 5);
+one_of_group!(OneOf7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+one_of_group!(OneOf8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+one_of_group!(OneOf9: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+one_of_group!(OneOf10: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+one_of_group!(OneOf11: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+one_of_group!(OneOf12: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+one_of_group!(OneOf13: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12);
+one_of_group!(OneOf14: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13);
+one_of_group!(OneOf15: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14);
+one_of_group!(OneOf16: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15);
+one_of_group!(OneOf17: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15, Q 16);
+one_of_group!(OneOf18: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15, Q 16, R 17);
+one_of_group!(OneOf19: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15, Q 16, R 17, S 18);
+one_of_group!(OneOf20: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15, Q 16, R 17, S 18, T 19);
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenTree};
+use syn::{
+    buffer::Cursor,
+    meta::ParseNestedMeta,
+    parse::{ParseStream, Parser},
+    spanned::Spanned,
+    Error, Result, Token,
+};
+
+use crate::origins::{name_attributes, Origin};
+
+use super::{diagnostics::not_accepted_error, path_to_string, ParseMeta};
+
+pub(crate) fn parse_meta<M>(
+    mut meta: M,
+    input: ParseStream,
+    accumulate: bool,
+    given_positionally: &[String],
+    origins: Option<&[Origin]>,
+) -> Result<M::Output>
+where
+    M: ParseMeta,
+{
+    let mut errors: Option<Error> = None;
+    let mut failed_paths = vec![];
+    let mut specified_paths = HashMap::new();
+    let mut items = origins.unwrap_or_default().iter();
+
+    let cursor = input.cursor();
+    let result = syn::meta::parser(|nested| {
+        let id = path_to_string(&nested.path);
+        let origin = items.next();
+        meta.set_origin(origin);
+        let result = parse_nested(
+            &mut meta,
+            &nested,
+            &id,
+            &mut specified_paths,
+            given_positionally,
+            origin,
+        )
+        .inspect_err(|_| {
+            failed_paths.push(id);
+        });
+
+        match result {
+            Err(err) if accumulate => {
+                push_error(&mut errors, err);
+                skip_nested(nested.input);
+                Ok(())
+            }
+            result => result,
+        }
+    })
+    .parse2(cursor.token_stream());
+
+    // set input buffer to empty
+    input.step(|_| Ok(((), Cursor::empty()))).unwrap();
+
+    if let Err(err) = result {
+        push_error(&mut errors, err);
+    }
+
+    if !accumulate {
+        if let Some(err) = errors {
+            return Err(err);
+        }
+        return meta.finish();
+    }
+
+    match errors {
+        None => meta.finish(),
+        Some(mut errors) => {
+            if let Some(err) = meta.finish_errors(&failed_paths) {
+                errors.combine(err);
+            }
+            Err(errors)
+        }
+    }
+}
+
+fn parse_nested<'a, M>(
+    meta: &mut M,
+    nested: &ParseNestedMeta,
+    id: &str,
+    specified_paths: &mut HashMap<String, (Span, Option<&'a Origin>)>,
+    given_positionally: &[String],
+    origin: Option<&'a Origin>,
+) -> Result<()>
+where
+    M: ParseMeta,
+{
+    if let (Some(&(first, first_origin)), false) =
+        (specified_paths.get(id), is_repeatable(meta, id))
+    {
+        let mut message = if given_positionally.iter().any(|key| key == id) {
+            format!("`{id}` has already been given as a positional argument")
+        } else {
+            format!("path `{id}` has been specified")
+        };
+        let mut note = "first specified here".to_string();
+        name_attributes(&mut message, &mut note, origin, first_origin);
+
+        let mut err = Error::new_spanned(&nested.path, message);
+        err.combine(Error::new(first, note));
+        return Err(err);
+    }
+
+    let parsed = meta.parse(nested);
+    if !matches!(parsed, Ok(false)) {
+        specified_paths
+            .entry(id.to_string())
+            .or_insert_with(|| (nested.path.span(), origin));
+    }
+
+    if !parsed? {
+        return Err(not_accepted_error(meta, nested, id));
+    }
+
+    if !nested.input.is_empty() && !nested.input.peek(Token![,]) {
+        return Err(nested.input.error("expected `,`"));
+    }
+
+    Ok(())
+}
+
+fn is_repeatable<M>(meta: &M, id: &str) -> bool
+where
+    M: ParseMeta,
+{
+    let mut found = false;
+    let mut repeatable = true;
+    meta.visit_keys(&mut |info| {
+        if info.key == id {
+            found = true;
+            repeatable &= info.repeatable;
+        }
+    });
+    found && repeatable
+}
+
+fn skip_nested(input: ParseStream) {
+    input
+        .step(|cursor| {
+            let mut rest = *cursor;
+            while let Some((token, next)) = rest.token_tree() {
+                if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                    break;
+                }
+                rest = next;
+            }
+            Ok(((), rest))
+        })
+        .unwrap();
+}
+
+pub(crate) fn push_error(errors: &mut Option<Error>, err: Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
+    }
+}
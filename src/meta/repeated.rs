@@ -0,0 +1,126 @@
+use std::fmt::Write;
+
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, spanned::Spanned, Error, Result};
+
+use super::{path_to_string, KeyInfo, Origin, ParseMeta};
+
+pub struct Repeated<T>
+where
+    T: ParseMeta,
+{
+    template: T,
+    values: Vec<T::Output>,
+    last_span: Option<Span>,
+    min: usize,
+    max: Option<usize>,
+}
+
+impl<T> Clone for Repeated<T>
+where
+    T: ParseMeta + Clone,
+    T::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Repeated {
+            template: self.template.clone(),
+            values: self.values.clone(),
+            last_span: self.last_span,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+impl<T> Repeated<T>
+where
+    T: ParseMeta + Clone,
+{
+    pub(crate) fn new(template: T) -> Self {
+        Repeated {
+            template,
+            values: vec![],
+            last_span: None,
+            min: 0,
+            max: None,
+        }
+    }
+
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl<T> ParseMeta for Repeated<T>
+where
+    T: ParseMeta + Clone,
+{
+    type Output = Vec<T::Output>;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.template.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.template.visit_keys(&mut |info| {
+            f(&KeyInfo {
+                repeatable: true,
+                ..*info
+            })
+        })
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.template.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let mut parser = self.template.clone();
+        if !parser.parse(nested)? {
+            return Ok(false);
+        }
+
+        if let Some(max) = self.max {
+            if self.values.len() >= max {
+                return Err(Error::new_spanned(
+                    &nested.path,
+                    format!(
+                        "attribute `{}` can be specified at most {max} times",
+                        path_to_string(&nested.path)
+                    ),
+                ));
+            }
+        }
+
+        self.values.push(parser.finish()?);
+        self.last_span = Some(nested.path.span());
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        if self.values.len() >= self.min {
+            return Ok(self.values);
+        }
+
+        let mut name = String::new();
+        self.template.conflict_alternative_arm(&mut name).unwrap();
+        Err(Error::new(
+            self.last_span.unwrap_or_else(Span::call_site),
+            format!(
+                "attribute {name} must be specified at least {} times, found {}",
+                self.min,
+                self.values.len()
+            ),
+        ))
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.values.len() >= self.min
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    fmt::{Display, Write},
+    ops::Deref,
+};
+
+use proc_macro2::{Punct, Spacing, Span, TokenStream, TokenTree};
+use syn::{buffer::Cursor, meta::ParseNestedMeta, spanned::Spanned as _, Error, Result};
+
+use super::{KeyInfo, Origin, ParseMeta};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub key_span: Span,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+#[derive(Clone)]
+pub struct WithSpan<T> {
+    parser: T,
+    spans: Option<(Span, Span)>,
+}
+
+impl<T> WithSpan<T> {
+    pub(crate) fn new(parser: T) -> Self {
+        WithSpan {
+            parser,
+            spans: None,
+        }
+    }
+}
+
+impl<T> ParseMeta for WithSpan<T>
+where
+    T: ParseMeta,
+{
+    type Output = Spanned<T::Output>;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let begin = nested.input.cursor();
+        if !self.parser.parse(nested)? {
+            return Ok(false);
+        }
+
+        let occurrence = Occurrence::new(nested, begin);
+        self.spans = Some((occurrence.key, occurrence.span()));
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        let (key_span, span) = self
+            .spans
+            .unwrap_or_else(|| (Span::call_site(), Span::call_site()));
+
+        Ok(Spanned {
+            value: self.parser.finish()?,
+            key_span,
+            span,
+        })
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        self.parser.finish_errors(failed)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.parser.ok_to_finish()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Occurrence {
+    key: Span,
+    start: Span,
+    end: Span,
+}
+
+impl Occurrence {
+    pub(crate) fn new(nested: &ParseNestedMeta, begin: Cursor) -> Self {
+        let key = nested.path.span();
+        let start = nested
+            .path
+            .leading_colon
+            .map(|colon| colon.spans[0])
+            .or_else(|| nested.path.segments.first().map(|seg| seg.ident.span()))
+            .unwrap_or(key);
+
+        let end_cursor = nested.input.cursor();
+        let mut cursor = begin;
+        let mut end = key;
+        while cursor != end_cursor {
+            let Some((token, next)) = cursor.token_tree() else {
+                break;
+            };
+            end = token.span();
+            cursor = next;
+        }
+
+        Occurrence { key, start, end }
+    }
+
+    pub(crate) fn span(&self) -> Span {
+        self.start.join(self.end).unwrap_or(self.start)
+    }
+
+    pub(crate) fn error<T: Display>(&self, message: T) -> Error {
+        let mut first = Punct::new('.', Spacing::Alone);
+        let mut last = Punct::new('.', Spacing::Alone);
+        first.set_span(self.start);
+        last.set_span(self.end);
+
+        let tokens = TokenStream::from_iter([TokenTree::from(first), TokenTree::from(last)]);
+        Error::new_spanned(tokens, message)
+    }
+}
@@ -1,10 +1,13 @@
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 
-use syn::{meta::ParseNestedMeta, Result};
+use syn::{meta::ParseNestedMeta, Error, Result};
 
 use crate::{Marker, ParseArgs};
 
-use super::{list, List, ParseMeta, ParseMetaUnnamed};
+use super::{
+    list, Constrained, Forms, KeyInfo, List, Origin, ParseMeta, ParseMetaUnnamed, Repeated, TryMap,
+    Validate, WithSpan,
+};
 
 pub trait ParseMetaExt: ParseMeta + Sized {
     fn optional(self) -> Optional<Self> {
@@ -18,13 +21,77 @@ pub trait ParseMetaExt: ParseMeta + Sized {
         Map { parser: self, map }
     }
 
+    fn try_map<F, R>(self, map: F) -> TryMap<Self, F>
+    where
+        F: FnOnce(Self::Output) -> Result<R>,
+    {
+        TryMap::new(self, map)
+    }
+
+    fn validate<F, E>(self, validate: F) -> Validate<Self, F>
+    where
+        F: FnOnce(&Self::Output) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        Validate::new(self, validate)
+    }
+
     fn value<U>(self, value: U) -> impl ParseMeta<Output = U> {
         self.map(move |_| value)
     }
+
+    fn default_with<F>(self, default: F) -> DefaultWith<Self, F>
+    where
+        F: FnOnce() -> Self::Output,
+    {
+        DefaultWith {
+            parser: self,
+            default,
+        }
+    }
+
+    fn default_value(self, value: Self::Output) -> impl ParseMeta<Output = Self::Output> {
+        self.default_with(move || value)
+    }
+
+    fn or_default(self) -> impl ParseMeta<Output = Self::Output>
+    where
+        Self::Output: Default,
+    {
+        self.default_with(Default::default)
+    }
+
+    fn spanned(self) -> WithSpan<Self> {
+        WithSpan::new(self)
+    }
+
+    fn requires(self, key: &str, required: &str) -> Constrained<Self> {
+        Constrained::new(self).requires(key, required)
+    }
+
+    fn excludes(self, key: &str, excluded: &str) -> Constrained<Self> {
+        Constrained::new(self).excludes(key, excluded)
+    }
+
+    fn required_unless(self, key: &str, unless: &str) -> Constrained<Self> {
+        Constrained::new(self).required_unless(key, unless)
+    }
+
+    fn repeated(self) -> Repeated<Self>
+    where
+        Self: Clone,
+    {
+        Repeated::new(self)
+    }
+
+    fn positional(self) -> Positional<Self> {
+        Positional(self)
+    }
 }
 
 impl<T> ParseMetaExt for T where T: ParseMeta + Sized {}
 
+#[derive(Clone)]
 pub struct Optional<T>(T);
 
 impl<T> ParseMeta for Optional<T>
@@ -37,6 +104,14 @@ where
         self.0.conflict_alternative_arm(f)
     }
 
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.0.set_origin(origin)
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         self.0.parse(nested)
     }
@@ -51,11 +126,113 @@ where
         Ok(opt)
     }
 
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        if self.0.ok_to_finish() {
+            self.0.finish_errors(failed)
+        } else {
+            None
+        }
+    }
+
     fn ok_to_finish(&self) -> bool {
         true
     }
 }
 
+#[derive(Clone)]
+pub struct Positional<T>(T);
+
+impl<T> ParseMeta for Positional<T>
+where
+    T: ParseMeta,
+{
+    type Output = T::Output;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.0.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_keys(&mut |info| {
+            f(&KeyInfo {
+                positional: true,
+                ..*info
+            })
+        })
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.0.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        self.0.parse(nested)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        self.0.finish()
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        self.0.finish_errors(failed)
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.0.ok_to_finish()
+    }
+}
+
+#[derive(Clone)]
+pub struct DefaultWith<T, F> {
+    parser: T,
+    default: F,
+}
+
+impl<T, F> ParseMeta for DefaultWith<T, F>
+where
+    T: ParseMeta,
+    F: FnOnce() -> T::Output,
+{
+    type Output = T::Output;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        self.parser.parse(nested)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        if self.parser.ok_to_finish() {
+            self.parser.finish()
+        } else {
+            Ok((self.default)())
+        }
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        if self.parser.ok_to_finish() {
+            self.parser.finish_errors(failed)
+        } else {
+            None
+        }
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone)]
 pub struct Map<T, F> {
     parser: T,
     map: F,
@@ -68,6 +245,10 @@ where
 {
     type Output = R;
 
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         self.parser.parse(nested)
     }
@@ -76,10 +257,18 @@ where
         self.parser.conflict_alternative_arm(f)
     }
 
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
     fn finish(self) -> Result<Self::Output> {
         self.parser.finish().map(self.map)
     }
 
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        self.parser.finish_errors(failed)
+    }
+
     fn ok_to_finish(&self) -> bool {
         self.parser.ok_to_finish()
     }
@@ -92,16 +281,36 @@ where
     MetaList(list(ParseArgs::new().meta(p)))
 }
 
-pub struct MetaList<P>(List<ParseArgs<Marker<()>, Marker<()>, Marker<()>, P>>)
+type MetaOnly<P> = ParseArgs<Marker<()>, Marker<()>, Marker<()>, P>;
+
+pub struct MetaList<P>(List<MetaOnly<P>>)
 where
     P: ParseMeta;
 
+impl<P> Clone for MetaList<P>
+where
+    P: ParseMeta + Clone,
+    P::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        MetaList(self.0.clone())
+    }
+}
+
 impl<P> ParseMetaUnnamed for MetaList<P>
 where
     P: ParseMeta,
 {
     type Output = P::Output;
 
+    fn forms(&self) -> Forms {
+        self.0.forms()
+    }
+
+    fn visit_nested_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.0.visit_nested_keys(f)
+    }
+
     fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
         self.0.parse(nested)
     }
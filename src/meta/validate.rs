@@ -0,0 +1,145 @@
+use std::fmt::{Display, Write};
+
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, Error, Result};
+
+use super::{declares_any, spanned::Occurrence, KeyInfo, Origin, ParseMeta};
+
+#[derive(Clone)]
+pub struct TryMap<T, F> {
+    parser: T,
+    map: F,
+    occurrence: Option<Occurrence>,
+}
+
+impl<T, F> TryMap<T, F> {
+    pub(crate) fn new(parser: T, map: F) -> Self {
+        TryMap {
+            parser,
+            map,
+            occurrence: None,
+        }
+    }
+}
+
+impl<T, F, R> ParseMeta for TryMap<T, F>
+where
+    T: ParseMeta,
+    F: FnOnce(T::Output) -> Result<R>,
+{
+    type Output = R;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let begin = nested.input.cursor();
+        let parsed = self.parser.parse(nested)?;
+        if parsed {
+            self.occurrence = Some(Occurrence::new(nested, begin));
+        }
+        Ok(parsed)
+    }
+
+    // the closure's error keeps its own span, which usually points into the value
+    fn finish(self) -> Result<Self::Output> {
+        let occurrence = self.occurrence;
+        (self.map)(self.parser.finish()?).map_err(|mut err| {
+            if let Some(occurrence) = occurrence {
+                err.combine(occurrence.error("for the value given here"));
+            }
+            err
+        })
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        if declares_any(&self.parser, failed) {
+            self.parser.finish_errors(failed)
+        } else {
+            self.finish().err()
+        }
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.parser.ok_to_finish()
+    }
+}
+
+#[derive(Clone)]
+pub struct Validate<T, F> {
+    parser: T,
+    validate: F,
+    occurrence: Option<Occurrence>,
+}
+
+impl<T, F> Validate<T, F> {
+    pub(crate) fn new(parser: T, validate: F) -> Self {
+        Validate {
+            parser,
+            validate,
+            occurrence: None,
+        }
+    }
+}
+
+impl<T, F, E> ParseMeta for Validate<T, F>
+where
+    T: ParseMeta,
+    F: FnOnce(&T::Output) -> std::result::Result<(), E>,
+    E: Display,
+{
+    type Output = T::Output;
+
+    fn conflict_alternative_arm(&self, f: &mut dyn Write) -> std::fmt::Result {
+        self.parser.conflict_alternative_arm(f)
+    }
+
+    fn visit_keys(&self, f: &mut dyn FnMut(&KeyInfo)) {
+        self.parser.visit_keys(f)
+    }
+
+    fn set_origin(&mut self, origin: Option<&Origin>) {
+        self.parser.set_origin(origin)
+    }
+
+    fn parse(&mut self, nested: &ParseNestedMeta) -> Result<bool> {
+        let begin = nested.input.cursor();
+        let parsed = self.parser.parse(nested)?;
+        if parsed {
+            self.occurrence = Some(Occurrence::new(nested, begin));
+        }
+        Ok(parsed)
+    }
+
+    fn finish(self) -> Result<Self::Output> {
+        let output = self.parser.finish()?;
+        match (self.validate)(&output) {
+            Ok(()) => Ok(output),
+            Err(message) => Err(match self.occurrence {
+                Some(occurrence) => occurrence.error(message),
+                None => Error::new(Span::call_site(), message),
+            }),
+        }
+    }
+
+    fn finish_errors(self, failed: &[String]) -> Option<Error> {
+        if declares_any(&self.parser, failed) {
+            self.parser.finish_errors(failed)
+        } else {
+            self.finish().err()
+        }
+    }
+
+    fn ok_to_finish(&self) -> bool {
+        self.parser.ok_to_finish()
+    }
+}
@@ -1,40 +1,52 @@
 use impl_variadics::impl_variadics;
 use syn::{
     parse::{Parse, ParseStream},
-    token::Token,
-    Result,
+    Error, Result,
 };
 
-use crate::with_comma;
+use crate::{parse_positional, with_comma};
 
 pub trait ParseOptionalArgs {
     type Output;
+    const ARITY: Option<usize> = None;
+
     fn parse(input: ParseStream) -> Result<Self::Output>;
+
+    // Also returns why the first missing argument did not parse, if a value was there.
+    fn parse_partial(input: ParseStream) -> Result<(Self::Output, Option<Error>)> {
+        Ok((Self::parse(input)?, None))
+    }
 }
 
 impl_variadics! {
     ..21 "T*" => {
         impl<#(#T0,)*> ParseOptionalArgs for (#(#T0,)*)
         where
-            #(#T0: Parse + Token,)*
+            #(#T0: Parse,)*
         {
             type Output = (#(Option<#T0>,)*);
+            const ARITY: Option<usize> = Some(#length);
+
+            fn parse(input: ParseStream) -> Result<Self::Output> {
+                Self::parse_partial(input).map(|(output, _)| output)
+            }
 
-            fn parse(_input: ParseStream) -> Result<Self::Output> {
+            fn parse_partial(_input: ParseStream) -> Result<(Self::Output, Option<Error>)> {
                 #[allow(unused_mut)]
                 let mut output: Self::Output = (#(None,)*);
 
                 #(
-                    match _input.parse::<Option<#T0>>()? {
-                        v @ Some(_) => {
+                    match parse_positional::<#T0>(_input) {
+                        Ok(v @ Some(_)) => {
                             output.#index = v;
                             with_comma(_input)?;
                         },
-                        None => return Ok(output),
+                        Ok(None) => return Ok((output, None)),
+                        Err(err) => return Ok((output, Some(err))),
                     }
                 )*
 
-                Ok(output)
+                Ok((output, None))
             }
         }
     }
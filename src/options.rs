@@ -0,0 +1,20 @@
+use syn::{parse::ParseStream, Result};
+
+use crate::{meta::KeyInfo, origins::Origins};
+
+// How a `ParseArgs` parser runs, set by the wrappers around it.
+#[derive(Clone, Copy, Default)]
+pub struct ParseOptions<'a> {
+    pub(crate) accumulate: bool,
+    pub(crate) interleaved: bool,
+    pub(crate) origins: Option<&'a Origins>,
+}
+
+// Implemented by `ParseArgs` and by `AccumulateErrors` and `Interleaved` around
+// it, which only change the options before passing them on.
+pub trait ParseWithOptions: Sized {
+    type Output;
+
+    fn parse_with(self, input: ParseStream, options: ParseOptions) -> Result<Self::Output>;
+    fn visit_meta_keys(&self, f: &mut dyn FnMut(&KeyInfo));
+}
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use proc_macro2::TokenTree;
+use syn::{buffer::Cursor, Attribute};
+
+use crate::meta::path_to_string;
+
+// Tracks which attribute each token of a `parse_concat_attrs` stream came from,
+// so that errors between keys of different attributes can point at both.
+pub(crate) struct Origins {
+    attrs: Vec<AttrOrigin>,
+    total: usize,
+}
+
+struct AttrOrigin {
+    name: String,
+    offset: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Origin {
+    index: usize,
+    name: String,
+}
+
+impl Origins {
+    pub(crate) fn new() -> Self {
+        Origins {
+            attrs: vec![],
+            total: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, attr: &Attribute, tokens: usize) {
+        self.attrs.push(AttrOrigin {
+            name: path_to_string(attr.path()),
+            offset: self.total,
+        });
+        self.total += tokens;
+    }
+
+    // The attribute of every comma-separated item from `cursor` on, which must
+    // be a suffix of the concatenated stream.
+    pub(crate) fn items(&self, mut cursor: Cursor) -> Vec<Origin> {
+        let mut items = vec![];
+        while !cursor.eof() {
+            items.push(self.attr_at(cursor));
+            while let Some((token, next)) = cursor.token_tree() {
+                cursor = next;
+                if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                    break;
+                }
+            }
+        }
+        items
+    }
+
+    fn attr_at(&self, cursor: Cursor) -> Origin {
+        let offset = self.total.saturating_sub(remaining(cursor));
+        let index = self
+            .attrs
+            .iter()
+            .rposition(|attr| attr.offset <= offset)
+            .unwrap_or(0);
+        Origin {
+            index,
+            name: self.attrs[index].name.clone(),
+        }
+    }
+}
+
+// Names both attributes when the keys an error is about come from different ones.
+pub(crate) fn name_attributes(
+    message: &mut String,
+    note: &mut String,
+    current: Option<&Origin>,
+    earlier: Option<&Origin>,
+) {
+    if let (Some(current), Some(earlier)) = (current, earlier) {
+        if current.index != earlier.index {
+            write!(message, ", here in `#[{}]`", current.name).unwrap();
+            write!(note, ", in an earlier `#[{}]` attribute", earlier.name).unwrap();
+        }
+    }
+}
+
+fn remaining(mut cursor: Cursor) -> usize {
+    let mut count = 0;
+    while let Some((_, next)) = cursor.token_tree() {
+        count += 1;
+        cursor = next;
+    }
+    count
+}
@@ -1,16 +1,38 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+    ops::Deref,
+};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
 use syn::{
+    buffer::Cursor,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     token::Token,
-    Result,
+    Error, Result, Token,
 };
 
-use crate::with_comma;
+use crate::{parse_positional_until, starts_meta_item, starts_path, with_comma};
 
 pub trait ParseRestArgs: Sized {
+    const ARITY: Option<usize> = None;
+
     fn parse(input: ParseStream) -> Result<Self>;
 }
 
+pub trait RestCollection: ParseRestArgs {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl ParseRestArgs for () {
+    const ARITY: Option<usize> = Some(0);
+
     fn parse(_: ParseStream) -> Result<Self> {
         Ok(())
     }
@@ -18,14 +40,221 @@ impl ParseRestArgs for () {
 
 impl<P> ParseRestArgs for Vec<P>
 where
-    P: Parse + Token,
+    P: Parse,
 {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut vec = vec![];
-        while let Some(v) = input.parse::<Option<P>>()? {
+        while let Some(v) = parse_rest_value::<P>(input, |input| input.peek(Token![,]))? {
             vec.push(v);
             with_comma(input)?;
         }
         Ok(vec)
     }
 }
+
+// A value that does not parse is an error, unless it is missing or a bare path,
+// which may still be a flag for the meta parser.
+fn parse_rest_value<P>(input: ParseStream, end: impl Fn(ParseStream) -> bool) -> Result<Option<P>>
+where
+    P: Parse,
+{
+    match parse_positional_until(input, end) {
+        Err(_) if input.peek(Token![,]) || starts_path(input.cursor()) => Ok(None),
+        result => result,
+    }
+}
+
+impl<P> RestCollection for Vec<P>
+where
+    P: Parse,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<P> ParseRestArgs for HashSet<P>
+where
+    P: Parse + ToTokens + Hash + Eq,
+{
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut set = HashSet::new();
+        for value in Vec::<P>::parse(input)? {
+            if set.contains(&value) {
+                return Err(duplicate_error(&value));
+            }
+            set.insert(value);
+        }
+        Ok(set)
+    }
+}
+
+impl<P> RestCollection for HashSet<P>
+where
+    P: Parse + ToTokens + Hash + Eq,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<P> ParseRestArgs for BTreeSet<P>
+where
+    P: Parse + ToTokens + Ord,
+{
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut set = BTreeSet::new();
+        for value in Vec::<P>::parse(input)? {
+            if set.contains(&value) {
+                return Err(duplicate_error(&value));
+            }
+            set.insert(value);
+        }
+        Ok(set)
+    }
+}
+
+impl<P> RestCollection for BTreeSet<P>
+where
+    P: Parse + ToTokens + Ord,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+fn duplicate_error<P>(value: &P) -> Error
+where
+    P: ToTokens,
+{
+    Error::new_spanned(
+        value,
+        format!("duplicate value `{}`", value.to_token_stream()),
+    )
+}
+
+impl<P, Sep> ParseRestArgs for Punctuated<P, Sep>
+where
+    P: Parse,
+    Sep: Parse + Token,
+{
+    fn parse(input: ParseStream) -> Result<Self> {
+        let end = |input: ParseStream| input.peek(Token![,]) || Sep::peek(input.cursor());
+        let mut punctuated = Punctuated::new();
+
+        let Some(first) = parse_rest_value::<P>(input, end)? else {
+            return Ok(punctuated);
+        };
+        punctuated.push_value(first);
+
+        while Sep::peek(input.cursor()) {
+            punctuated.push_punct(input.parse()?);
+            match parse_rest_value::<P>(input, end)? {
+                Some(value) => punctuated.push_value(value),
+                None => break,
+            }
+        }
+
+        // a comma separator may have been taken as trailing punctuation already
+        if !punctuated.trailing_punct() || input.peek(Token![,]) {
+            with_comma(input)?;
+        }
+        Ok(punctuated)
+    }
+}
+
+impl<P, Sep> RestCollection for Punctuated<P, Sep>
+where
+    P: Parse,
+    Sep: Parse + Token,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl ParseRestArgs for TokenStream {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.step(|cursor| {
+            let mut tokens = TokenStream::new();
+            let mut rest = *cursor;
+
+            while !rest.eof() && !starts_meta_item(rest) {
+                while let Some((token, next)) = rest.token_tree() {
+                    rest = next;
+                    if matches!(&token, TokenTree::Punct(p) if p.as_char() == ',') {
+                        if !rest.eof() && !starts_meta_item(rest) {
+                            tokens.extend([token]);
+                        }
+                        break;
+                    }
+                    tokens.extend([token]);
+                }
+            }
+
+            Ok((tokens, rest))
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bounded<T, const MIN: usize, const MAX: usize = { usize::MAX }>(pub T);
+
+impl<T, const MIN: usize, const MAX: usize> Bounded<T, MIN, MAX> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for Bounded<T, MIN, MAX> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> ParseRestArgs for Bounded<T, MIN, MAX>
+where
+    T: RestCollection,
+{
+    fn parse(input: ParseStream) -> Result<Self> {
+        let begin = input.cursor();
+        let rest = T::parse(input)?;
+        let len = rest.len();
+
+        let message = if len < MIN {
+            format!("expected at least {MIN} positional values, found {len}")
+        } else if len > MAX {
+            format!("expected at most {MAX} positional values, found {len}")
+        } else {
+            return Ok(Bounded(rest));
+        };
+
+        Err(match tokens_between(begin, input.cursor()) {
+            tokens if tokens.is_empty() => Error::new(input.span(), message),
+            tokens => Error::new_spanned(tokens, message),
+        })
+    }
+}
+
+fn tokens_between(mut begin: Cursor, end: Cursor) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    while begin < end {
+        let Some((token, next)) = begin.token_tree() else {
+            break;
+        };
+        tokens.extend([token]);
+        begin = next;
+    }
+    tokens
+}
+
+impl<T, const MIN: usize, const MAX: usize> RestCollection for Bounded<T, MIN, MAX>
+where
+    T: RestCollection,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}